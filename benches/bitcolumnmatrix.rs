@@ -0,0 +1,151 @@
+//! Benchmark of `BitColumnMatrix` multiplication, and the jumpahead that depends on it.
+//!
+//! Run with `cargo bench`. This uses no benchmark framework, just timing of many iterations.
+
+use num_traits::{One, Pow};
+use rand_core::RngCore;
+use simplerandom::bitcolumnmatrix::BitColumnMatrix;
+use simplerandom::RngJumpAhead;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Time `iterations` calls of `f`, returning the time per call.
+///
+/// This is repeated a few times, and the fastest is used, to reduce noise from other activity.
+fn time_per_call<F: FnMut()>(iterations: u32, mut f: F) -> Duration {
+    (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                f();
+            }
+            start.elapsed() / iterations
+        })
+        .min()
+        .unwrap()
+}
+
+fn random_matrix<T, const WIDTH: usize>(
+    rng: &mut simplerandom::SplitMix64,
+) -> BitColumnMatrix<T, WIDTH>
+where
+    T: simplerandom::bitcolumnmatrix::BitColumnMatrixInt,
+{
+    BitColumnMatrix::new(&[(); WIDTH].map(|_| {
+        let value = ((rng.next_u64() as u128) << 64) | rng.next_u64() as u128;
+        T::from(value & (u128::MAX >> (128 - WIDTH))).unwrap()
+    }))
+}
+
+fn bench_dot<T, const WIDTH: usize>(name: &str, iterations: u32)
+where
+    T: simplerandom::bitcolumnmatrix::BitColumnMatrixInt,
+{
+    let mut rng = simplerandom::SplitMix64::new(1);
+    let a = random_matrix::<T, WIDTH>(&mut rng);
+    let b = random_matrix::<T, WIDTH>(&mut rng);
+    assert!(a.dot(&b) == a.dot_naive(&b));
+    let naive = time_per_call(iterations, || {
+        black_box(black_box(&a).dot_naive(black_box(&b)));
+    });
+    let four_russians = time_per_call(iterations, || {
+        black_box(black_box(&a).dot(black_box(&b)));
+    });
+    println!(
+        "{:<24} dot: naive {:>10.2?}  four russians {:>10.2?}  speedup {:.1}×",
+        name,
+        naive,
+        four_russians,
+        naive.as_secs_f64() / four_russians.as_secs_f64()
+    );
+}
+
+fn bench_dot_vec<T, const WIDTH: usize>(name: &str, iterations: u32)
+where
+    T: simplerandom::bitcolumnmatrix::BitColumnMatrixInt,
+{
+    let mut rng = simplerandom::SplitMix64::new(3);
+    let a = random_matrix::<T, WIDTH>(&mut rng);
+    let table = a.dot_table();
+    let x = a.dot_vec(T::max_value());
+    assert!(table.dot_vec(x) == a.dot_vec(x));
+    let naive = time_per_call(iterations, || {
+        black_box(black_box(&a).dot_vec(black_box(x)));
+    });
+    let byte_table = time_per_call(iterations, || {
+        black_box(black_box(&table).dot_vec(black_box(x)));
+    });
+    println!(
+        "{:<24} dot_vec: naive {:>10.2?}  byte table {:>10.2?}  speedup {:.1}×",
+        name,
+        naive,
+        byte_table,
+        naive.as_secs_f64() / byte_table.as_secs_f64()
+    );
+}
+
+/// Matrix power by the same method as `Pow`, but with `dot_naive()`.
+fn pow_naive<T, const WIDTH: usize>(
+    a: &BitColumnMatrix<T, WIDTH>,
+    n: u64,
+) -> BitColumnMatrix<T, WIDTH>
+where
+    T: simplerandom::bitcolumnmatrix::BitColumnMatrixInt,
+{
+    let mut result = BitColumnMatrix::<T, WIDTH>::one();
+    let mut temp_exp = a.clone();
+    let mut n_work = n;
+    loop {
+        if n_work & 1 != 0 {
+            result = result.dot_naive(&temp_exp);
+        }
+        n_work >>= 1;
+        if n_work == 0 {
+            break;
+        }
+        temp_exp = temp_exp.dot_naive(&temp_exp);
+    }
+    result
+}
+
+fn bench_pow<T, const WIDTH: usize>(name: &str, iterations: u32)
+where
+    T: simplerandom::bitcolumnmatrix::BitColumnMatrixInt,
+{
+    let mut rng = simplerandom::SplitMix64::new(2);
+    let a = random_matrix::<T, WIDTH>(&mut rng);
+    let n = u64::MAX;
+    assert!(a.clone().pow(n) == pow_naive(&a, n));
+    let naive = time_per_call(iterations, || {
+        black_box(pow_naive(black_box(&a), black_box(n)));
+    });
+    let four_russians = time_per_call(iterations, || {
+        black_box(black_box(a.clone()).pow(black_box(n)));
+    });
+    println!(
+        "{:<24} pow(2^64-1): naive {:>10.2?}  four russians {:>10.2?}  speedup {:.1}×",
+        name,
+        naive,
+        four_russians,
+        naive.as_secs_f64() / four_russians.as_secs_f64()
+    );
+}
+
+fn bench_jumpahead<R: RngJumpAhead>(name: &str, iterations: u32, mut rng: R) {
+    let t = time_per_call(iterations, || {
+        rng.jumpahead(black_box(1_000_000_000_000_000_000_u64));
+    });
+    println!("{:<24} jumpahead: {:>10.2?}", name, t);
+}
+
+fn main() {
+    bench_dot::<u32, 32>("BitColumnMatrix<u32, 32>", 20_000);
+    bench_dot::<u64, 64>("BitColumnMatrix<u64, 64>", 5_000);
+    bench_dot::<u128, 128>("BitColumnMatrix<u128, 128>", 1_000);
+    bench_dot_vec::<u32, 32>("BitColumnMatrix<u32, 32>", 1_000_000);
+    bench_dot_vec::<u128, 128>("BitColumnMatrix<u128, 128>", 1_000_000);
+    bench_pow::<u32, 32>("BitColumnMatrix<u32, 32>", 1_000);
+    bench_pow::<u128, 128>("BitColumnMatrix<u128, 128>", 100);
+    bench_jumpahead("SHR3", 1_000, simplerandom::SHR3::new(1));
+    bench_jumpahead("LFSR113", 1_000, simplerandom::LFSR113::new(1, 2, 3, 4));
+}
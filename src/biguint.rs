@@ -0,0 +1,339 @@
+//! Arbitrary-size unsigned integers
+//!
+//! The value is represented by a vector of u32 limbs, least significant
+//! first. Only the operations needed for jumpahead of generators with very
+//! large moduli are provided, such as the lag-r multiply-with-carry
+//! generators, whose equivalent LCG modulus a*b^r±1 can be many thousands of
+//! bits. [`BigUint::pow_mod`] is the big-modulus counterpart of
+//! [`crate::maths::pow_mod`].
+
+use num_traits::Zero;
+use std::cmp::Ordering;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BigUint {
+    /// Limbs, least significant first. There are never any trailing zero limbs, so that a given
+    /// value has a unique representation.
+    limbs: Vec<u32>,
+}
+
+const LIMB_BITS: u32 = 32;
+
+impl BigUint {
+    /// Create a value from limbs, least significant first.
+    pub fn from_limbs(limbs: &[u32]) -> BigUint {
+        let mut result = BigUint {
+            limbs: limbs.to_vec(),
+        };
+        result.trim();
+        result
+    }
+
+    pub fn from_u64(a: u64) -> BigUint {
+        BigUint::from_limbs(&[a as u32, (a >> LIMB_BITS) as u32])
+    }
+
+    pub fn from_u128(a: u128) -> BigUint {
+        BigUint::from_limbs(&[a as u32, (a >> 32) as u32, (a >> 64) as u32, (a >> 96) as u32])
+    }
+
+    pub fn limbs(&self) -> &[u32] {
+        &self.limbs
+    }
+
+    /// The value as a u64, or `None` if it doesn't fit.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs.len() {
+            0 => Some(0),
+            1 => Some(self.limbs[0] as u64),
+            2 => Some(self.limbs[0] as u64 | ((self.limbs[1] as u64) << LIMB_BITS)),
+            _ => None,
+        }
+    }
+
+    fn trim(&mut self) {
+        while let Some(&0) = self.limbs.last() {
+            self.limbs.pop();
+        }
+    }
+
+    /// Number of significant bits.
+    pub fn bits(&self) -> usize {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() * LIMB_BITS as usize - top.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Shift left by `n` bits, where `n` is less than the limb size, always adding one extra limb.
+    fn shl_bits_extend(&self, n: u32) -> Vec<u32> {
+        let mut result = Vec::with_capacity(self.limbs.len() + 1);
+        let mut carry = 0_u32;
+        for limb in self.limbs.iter() {
+            if n == 0 {
+                result.push(*limb);
+            } else {
+                result.push((*limb << n) | carry);
+                carry = *limb >> (LIMB_BITS - n);
+            }
+        }
+        result.push(carry);
+        result
+    }
+
+    /// Quotient and remainder of division by a single limb.
+    fn div_rem_limb(&self, d: u32) -> (BigUint, u32) {
+        let mut quotient = vec![0_u32; self.limbs.len()];
+        let mut rem = 0_u64;
+        for (q, limb) in quotient.iter_mut().zip(self.limbs.iter()).rev() {
+            let num = (rem << LIMB_BITS) | *limb as u64;
+            *q = (num / d as u64) as u32;
+            rem = num % d as u64;
+        }
+        (BigUint::from_limbs(&quotient), rem as u32)
+    }
+
+    /// Quotient and remainder of division by `d`.
+    ///
+    /// This is Knuth's algorithm D (The Art of Computer Programming, vol. 2, 4.3.1). The time is
+    /// proportional to the product of the lengths of the quotient and `d`, so division with a
+    /// small quotient is fast even for large values.
+    ///
+    /// # Panics
+    ///
+    /// If `d` is zero.
+    pub fn div_rem(&self, d: &BigUint) -> (BigUint, BigUint) {
+        assert!(!d.is_zero(), "division by zero");
+        if *self < *d {
+            return (BigUint::zero(), self.clone());
+        }
+        if d.limbs.len() == 1 {
+            let (quotient, rem) = self.div_rem_limb(d.limbs[0]);
+            return (quotient, BigUint::from_u64(rem as u64));
+        }
+
+        // Normalise, so the top limb of the divisor has its top bit set.
+        let shift = d.limbs.last().unwrap().leading_zeros();
+        let mut v = d.shl_bits_extend(shift);
+        v.pop();
+        let mut u = self.shl_bits_extend(shift);
+        let n = v.len();
+        let m = u.len() - n - 1;
+        let v_top = v[n - 1] as u64;
+        let v_next = v[n - 2] as u64;
+        let base = 1_u64 << LIMB_BITS;
+        let mut quotient = vec![0_u32; m + 1];
+
+        for j in (0..=m).rev() {
+            // Estimate the quotient limb from the top limbs, which is at most 2 too large.
+            let num = ((u[j + n] as u64) << LIMB_BITS) | u[j + n - 1] as u64;
+            let mut q_hat = num / v_top;
+            let mut r_hat = num % v_top;
+            while q_hat >= base || q_hat * v_next > ((r_hat << LIMB_BITS) | u[j + n - 2] as u64) {
+                q_hat -= 1;
+                r_hat += v_top;
+                if r_hat >= base {
+                    break;
+                }
+            }
+
+            // Multiply and subtract.
+            let mut borrow = 0_i64;
+            let mut carry = 0_u64;
+            for i in 0..n {
+                let p = q_hat * v[i] as u64 + carry;
+                carry = p >> LIMB_BITS;
+                let t = u[i + j] as i64 - borrow - (p as u32) as i64;
+                u[i + j] = t as u32;
+                borrow = (t < 0) as i64;
+            }
+            let t = u[j + n] as i64 - borrow - carry as i64;
+            u[j + n] = t as u32;
+
+            if t < 0 {
+                // The estimate was 1 too large. Add back.
+                q_hat -= 1;
+                let mut carry = 0_u64;
+                for i in 0..n {
+                    let s = u[i + j] as u64 + v[i] as u64 + carry;
+                    u[i + j] = s as u32;
+                    carry = s >> LIMB_BITS;
+                }
+                u[j + n] = u[j + n].wrapping_add(carry as u32);
+            }
+            quotient[j] = q_hat as u32;
+        }
+
+        // Unnormalise the remainder.
+        let mut rem = vec![0_u32; n];
+        for i in 0..n {
+            rem[i] = if shift == 0 {
+                u[i]
+            } else {
+                (u[i] >> shift) | (u[i + 1] << (LIMB_BITS - shift))
+            };
+        }
+        (BigUint::from_limbs(&quotient), BigUint::from_limbs(&rem))
+    }
+
+    /// Remainder of division by `m`.
+    pub fn rem(&self, m: &BigUint) -> BigUint {
+        self.div_rem(m).1
+    }
+
+    /// Product of two values, modulo `m`.
+    pub fn mul_mod(&self, b: &BigUint, m: &BigUint) -> BigUint {
+        (self * b).rem(m)
+    }
+
+    /// Greatest common divisor, by Euclid's algorithm.
+    pub fn gcd(&self, b: &BigUint) -> BigUint {
+        let mut a = self.clone();
+        let mut b = b.clone();
+        while !b.is_zero() {
+            let r = a.rem(&b);
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    /// Least common multiple.
+    pub fn lcm(&self, b: &BigUint) -> BigUint {
+        if self.is_zero() || b.is_zero() {
+            return BigUint::zero();
+        }
+        &self.div_rem(&self.gcd(b)).0 * b
+    }
+
+    /// Raise the value to the power `n`.
+    pub fn pow(&self, n: u32) -> BigUint {
+        let mut result = BigUint::from_u64(1);
+        let mut bit = 32 - n.leading_zeros();
+        while bit > 0 {
+            bit -= 1;
+            result = &result * &result;
+            if (n >> bit) & 1 != 0 {
+                result = &result * self;
+            }
+        }
+        result
+    }
+
+    /// Raise the value to the power `n`, modulo `m`.
+    ///
+    /// This is done by left-to-right binary exponentiation.
+    pub fn pow_mod(&self, n: u128, m: &BigUint) -> BigUint {
+        let base = self.rem(m);
+        let mut result = BigUint::from_u64(1).rem(m);
+        let mut bit = 128 - n.leading_zeros();
+        while bit > 0 {
+            bit -= 1;
+            result = result.mul_mod(&result, m);
+            if (n >> bit) & 1 != 0 {
+                result = result.mul_mod(&base, m);
+            }
+        }
+        result
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, b: &BigUint) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&b.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(b.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, b: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(b))
+    }
+}
+
+impl Zero for BigUint {
+    /// Create a zero value.
+    fn zero() -> BigUint {
+        BigUint { limbs: Vec::new() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+}
+
+impl<'b> core::ops::Add<&'b BigUint> for &BigUint {
+    type Output = BigUint;
+
+    /// Add two values.
+    fn add(self, b: &'b BigUint) -> BigUint {
+        let (long, short) = if self.limbs.len() >= b.limbs.len() {
+            (&self.limbs, &b.limbs)
+        } else {
+            (&b.limbs, &self.limbs)
+        };
+        let mut limbs = Vec::with_capacity(long.len() + 1);
+        let mut carry = 0_u64;
+        for (i, limb) in long.iter().enumerate() {
+            let s = *limb as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+            limbs.push(s as u32);
+            carry = s >> LIMB_BITS;
+        }
+        limbs.push(carry as u32);
+        BigUint::from_limbs(&limbs)
+    }
+}
+
+impl core::ops::Add for BigUint {
+    type Output = Self;
+
+    /// Add two values.
+    fn add(self, b: BigUint) -> BigUint {
+        &self + &b
+    }
+}
+
+impl<'b> core::ops::Sub<&'b BigUint> for &BigUint {
+    type Output = BigUint;
+
+    /// Subtract two values.
+    ///
+    /// # Panics
+    ///
+    /// If `b` is greater than `self`.
+    fn sub(self, b: &'b BigUint) -> BigUint {
+        assert!(*self >= *b, "subtraction overflow");
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0_i64;
+        for (i, limb) in self.limbs.iter().enumerate() {
+            let t = *limb as i64 - b.limbs.get(i).copied().unwrap_or(0) as i64 - borrow;
+            limbs.push(t as u32);
+            borrow = (t < 0) as i64;
+        }
+        BigUint::from_limbs(&limbs)
+    }
+}
+
+impl<'b> core::ops::Mul<&'b BigUint> for &BigUint {
+    type Output = BigUint;
+
+    /// Multiply two values.
+    fn mul(self, b: &'b BigUint) -> BigUint {
+        if self.is_zero() || b.is_zero() {
+            return BigUint::zero();
+        }
+        let mut limbs = vec![0_u32; self.limbs.len() + b.limbs.len()];
+        for (i, a_limb) in self.limbs.iter().enumerate() {
+            let mut carry = 0_u64;
+            for (j, b_limb) in b.limbs.iter().enumerate() {
+                let p = *a_limb as u64 * *b_limb as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = p as u32;
+                carry = p >> LIMB_BITS;
+            }
+            limbs[i + b.limbs.len()] = carry as u32;
+        }
+        BigUint::from_limbs(&limbs)
+    }
+}
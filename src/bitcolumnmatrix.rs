@@ -4,6 +4,7 @@
 //! of a bit width that is suitable for the desired matrix dimensions.
 //! Eg for a 32×32 matrix, an array of u32 of length 32 is used.
 
+use crate::gf2poly::Gf2Poly;
 use num_traits::{ConstOne, ConstZero, One, Pow, PrimInt, Unsigned, Zero};
 use std::ops::{BitAnd, Shl, Shr};
 
@@ -29,6 +30,84 @@ where
         }
     }
 
+    /// Create the matrix of a function that is linear over Galois(2).
+    ///
+    /// Column i is `f` applied to the unit vector with only bit i set. When `f` is the state
+    /// transition of a linear generator, such as an xorshift or LFSR step, this gives its
+    /// transition matrix, which can be raised to a power for jumpahead.
+    pub fn from_linear_fn(f: impl Fn(T) -> T) -> BitColumnMatrix<T, WIDTH> {
+        let mut result = BitColumnMatrix::<T, WIDTH> {
+            columns: [T::ZERO; WIDTH],
+        };
+        for i in 0..WIDTH {
+            result.columns[i] = f(T::ONE << i);
+        }
+        result
+    }
+
+    /// Characteristic polynomial of the matrix, det(xI - M).
+    ///
+    /// The matrix is reduced to upper Hessenberg form by similarity transforms, then the
+    /// characteristic polynomial is found by Hessenberg's recurrence. See Cohen, "A Course in
+    /// Computational Algebraic Number Theory", algorithm 2.2.9. Over Galois(2), there are no
+    /// signs or divisions to worry about.
+    pub fn char_poly(&self) -> Gf2Poly {
+        // h[r][c] is the element at row r, column c.
+        let mut h = vec![vec![false; WIDTH]; WIDTH];
+        for (c, column) in self.columns.iter().enumerate() {
+            for (r, row) in h.iter_mut().enumerate() {
+                row[c] = (*column >> r) & T::ONE != T::ZERO;
+            }
+        }
+
+        // Reduce to Hessenberg form, with zeros below the sub-diagonal.
+        for m in 1..WIDTH.saturating_sub(1) {
+            let i = match (m..WIDTH).find(|&i| h[i][m - 1]) {
+                Some(i) => i,
+                None => continue,
+            };
+            if i != m {
+                h.swap(i, m);
+                for row in h.iter_mut() {
+                    row.swap(i, m);
+                }
+            }
+            for j in m + 1..WIDTH {
+                if h[j][m - 1] {
+                    // Subtract row m from row j, and add column j to column m.
+                    let row_m = h[m].clone();
+                    for (a, b) in h[j].iter_mut().zip(row_m.iter()) {
+                        *a ^= *b;
+                    }
+                    for row in h.iter_mut() {
+                        row[m] ^= row[j];
+                    }
+                }
+            }
+        }
+
+        // p[m] is the characteristic polynomial of the leading m×m sub-matrix.
+        let mut p = vec![Gf2Poly::from_words(&[1])];
+        for m in 1..=WIDTH {
+            let mut p_m = p[m - 1].shl(1);
+            if h[m - 1][m - 1] {
+                p_m = p_m + p[m - 1].clone();
+            }
+            let mut t = true;
+            for i in 1..m {
+                t = t && h[m - i][m - i - 1];
+                if !t {
+                    break;
+                }
+                if h[m - i - 1][m - 1] {
+                    p_m = p_m + p[m - i - 1].clone();
+                }
+            }
+            p.push(p_m);
+        }
+        p.pop().unwrap_or_default()
+    }
+
     pub fn shift(shift_value: i8) -> BitColumnMatrix<T, WIDTH> {
         let mut result = BitColumnMatrix::<T, WIDTH> {
             columns: [T::ZERO; WIDTH],
@@ -66,21 +145,122 @@ where
         result
     }
 
+    /// Matrix multiplication, by the Method of Four Russians.
+    ///
+    /// For each group of 4 columns of `self`, a table is made of the xor of every combination of
+    /// those columns. Then the group's contribution to each column of the result is a single
+    /// table lookup, rather than a conditional xor per bit. The result is identical to
+    /// [`dot_naive()`](Self::dot_naive), but faster for larger matrices.
     pub fn dot(&self, b: &BitColumnMatrix<T, WIDTH>) -> BitColumnMatrix<T, WIDTH> {
+        let nibble_mask = T::from(DOT_GROUP_SIZE - 1).unwrap_or(T::ZERO);
         let mut result = BitColumnMatrix::<T, WIDTH>::zero();
-        for i in 0..WIDTH {
-            result.columns[i] = self.dot_vec(b.columns[i]);
+        let mut table = [T::ZERO; DOT_GROUP_SIZE];
+        for (g, group) in self.columns.chunks(DOT_GROUP_BITS).enumerate() {
+            fill_dot_table_group(&mut table, group);
+            let shift = g * DOT_GROUP_BITS;
+            for (r, b_column) in result.columns.iter_mut().zip(b.columns.iter()) {
+                let index = ((*b_column >> shift) & nibble_mask).to_usize().unwrap_or(0);
+                *r ^= table[index % DOT_GROUP_SIZE];
+            }
         }
         result
     }
 
     pub fn dot_equ(&mut self, b: &BitColumnMatrix<T, WIDTH>) {
-        let a = BitColumnMatrix::<T, WIDTH> {
-            columns: self.columns,
-        };
+        *self = self.dot(b);
+    }
+
+    /// Matrix multiplication, by a `dot_vec()` for each column of `b`.
+    ///
+    /// This is simple, but slower than [`dot()`](Self::dot).
+    pub fn dot_naive(&self, b: &BitColumnMatrix<T, WIDTH>) -> BitColumnMatrix<T, WIDTH> {
+        let mut result = BitColumnMatrix::<T, WIDTH>::zero();
         for i in 0..WIDTH {
-            self.columns[i] = a.dot_vec(b.columns[i]);
+            result.columns[i] = self.dot_vec(b.columns[i]);
+        }
+        result
+    }
+
+    /// Make a lookup table for fast repeated multiplication of vectors by this matrix.
+    pub fn dot_table(&self) -> DotTable<T> {
+        let mut table = vec![T::ZERO; WIDTH.div_ceil(DOT_TABLE_BITS) * DOT_TABLE_SIZE];
+        for (table_group, group) in table
+            .chunks_exact_mut(DOT_TABLE_SIZE)
+            .zip(self.columns.chunks(DOT_TABLE_BITS))
+        {
+            fill_dot_table_group(table_group, group);
+        }
+        DotTable { table }
+    }
+
+    /// Transpose of the matrix.
+    pub fn transpose(&self) -> BitColumnMatrix<T, WIDTH> {
+        let mut result = BitColumnMatrix::<T, WIDTH>::zero();
+        for (c, column) in self.columns.iter().enumerate() {
+            for (r, result_column) in result.columns.iter_mut().enumerate() {
+                if (*column >> r) & T::ONE != T::ZERO {
+                    *result_column = *result_column | (T::ONE << c);
+                }
+            }
         }
+        result
+    }
+
+    /// Rank of the matrix, by Gaussian elimination.
+    pub fn rank(&self) -> usize {
+        let mut columns = self.columns;
+        let mut rank = 0;
+        for r in 0..WIDTH {
+            let bit = T::ONE << r;
+            if let Some(pivot) = (rank..WIDTH).find(|&c| columns[c] & bit != T::ZERO) {
+                columns.swap(rank, pivot);
+                let pivot_column = columns[rank];
+                for column in columns[rank + 1..].iter_mut() {
+                    if *column & bit != T::ZERO {
+                        *column ^= pivot_column;
+                    }
+                }
+                rank += 1;
+            }
+        }
+        rank
+    }
+
+    /// Determinant of the matrix, which over Galois(2) is either 0 (`false`) or 1 (`true`).
+    pub fn determinant(&self) -> bool {
+        self.rank() == WIDTH
+    }
+
+    /// Inverse of the matrix, by Gauss–Jordan elimination.
+    ///
+    /// Column operations reduce the matrix to the unity matrix. The same operations applied to
+    /// the unity matrix give the inverse. The result is `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<BitColumnMatrix<T, WIDTH>> {
+        let mut a = self.columns;
+        let mut result = BitColumnMatrix::<T, WIDTH>::one();
+        for r in 0..WIDTH {
+            let bit = T::ONE << r;
+            let pivot = (r..WIDTH).find(|&c| a[c] & bit != T::ZERO)?;
+            a.swap(r, pivot);
+            result.columns.swap(r, pivot);
+            let (a_pivot, result_pivot) = (a[r], result.columns[r]);
+            for (c, (a_column, result_column)) in
+                a.iter_mut().zip(result.columns.iter_mut()).enumerate()
+            {
+                if c != r && *a_column & bit != T::ZERO {
+                    *a_column ^= a_pivot;
+                    *result_column ^= result_pivot;
+                }
+            }
+        }
+        Some(result)
+    }
+
+    /// Solve M x = `b` for x.
+    ///
+    /// The result is `None` if the matrix is singular, so there is no unique solution.
+    pub fn solve(&self, b: T) -> Option<T> {
+        self.inverse().map(|inverse| inverse.dot_vec(b))
     }
 }
 
@@ -214,6 +394,7 @@ where
     type Output = Self;
 
     /// Add two matrices.
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn add(self, b: BitColumnMatrix<T, WIDTH>) -> BitColumnMatrix<T, WIDTH> {
         let mut result = BitColumnMatrix::<T, WIDTH> {
             columns: self.columns,
@@ -225,14 +406,15 @@ where
     }
 }
 
-impl<'a, 'b, T, const WIDTH: usize> core::ops::Add<&'b BitColumnMatrix<T, WIDTH>>
-    for &'a BitColumnMatrix<T, WIDTH>
+impl<'b, T, const WIDTH: usize> core::ops::Add<&'b BitColumnMatrix<T, WIDTH>>
+    for &BitColumnMatrix<T, WIDTH>
 where
     T: BitColumnMatrixInt,
 {
     type Output = BitColumnMatrix<T, WIDTH>;
 
     /// Add two matrices (by reference).
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn add(self, b: &'b BitColumnMatrix<T, WIDTH>) -> BitColumnMatrix<T, WIDTH> {
         let mut result = BitColumnMatrix::<T, WIDTH> {
             columns: self.columns,
@@ -256,8 +438,8 @@ where
     }
 }
 
-impl<'a, 'b, T, const WIDTH: usize> core::ops::Mul<&'b BitColumnMatrix<T, WIDTH>>
-    for &'a BitColumnMatrix<T, WIDTH>
+impl<'b, T, const WIDTH: usize> core::ops::Mul<&'b BitColumnMatrix<T, WIDTH>>
+    for &BitColumnMatrix<T, WIDTH>
 where
     T: BitColumnMatrixInt,
 {
@@ -268,3 +450,64 @@ where
         self.dot(b)
     }
 }
+
+/// Bits per lookup in `BitColumnMatrix::dot()`. The table for each group is made for a single
+/// matrix multiplication, so it is kept small.
+const DOT_GROUP_BITS: usize = 4;
+/// Number of entries in the table for each group in `BitColumnMatrix::dot()`.
+const DOT_GROUP_SIZE: usize = 1 << DOT_GROUP_BITS;
+/// Bits per lookup in a `DotTable`.
+const DOT_TABLE_BITS: usize = 8;
+/// Number of entries in each group of a `DotTable`.
+const DOT_TABLE_SIZE: usize = 1 << DOT_TABLE_BITS;
+
+/// Fill a table with the xor of every combination of the columns of `group`.
+///
+/// Each entry is the xor of the columns selected by the bits of its index. It is made from the
+/// entry without the lowest set bit of the index. Bits beyond the length of the group select
+/// nothing.
+fn fill_dot_table_group<T>(table: &mut [T], group: &[T])
+where
+    T: BitColumnMatrixInt,
+{
+    table[0] = T::ZERO;
+    for v in 1..table.len() {
+        let mut entry = table[v & (v - 1)];
+        if let Some(column) = group.get(v.trailing_zeros() as usize) {
+            entry ^= *column;
+        }
+        table[v] = entry;
+    }
+}
+
+/// Lookup table for multiplying vectors by a `BitColumnMatrix`
+///
+/// For each group of 8 columns of the matrix, there are 256 entries: the xor of the columns
+/// selected by each value of a byte. Then multiplying by a vector takes one lookup per byte of the
+/// vector, rather than a conditional xor per bit. Making the table costs about as much as 8
+/// `dot_vec()` calls of the matrix, so it is worthwhile for repeated multiplication.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DotTable<T>
+where
+    T: BitColumnMatrixInt,
+{
+    table: Vec<T>,
+}
+
+impl<T> DotTable<T>
+where
+    T: BitColumnMatrixInt,
+{
+    /// Multiply the matrix by vector `b`. The result is identical to `BitColumnMatrix::dot_vec()`.
+    pub fn dot_vec(&self, b: T) -> T {
+        let byte_mask = T::from(DOT_TABLE_SIZE - 1).unwrap_or(T::ZERO);
+        let mut result = T::ZERO;
+        for (i, group) in self.table.chunks_exact(DOT_TABLE_SIZE).enumerate() {
+            let byte = ((b >> (i * DOT_TABLE_BITS)) & byte_mask)
+                .to_usize()
+                .unwrap_or(0);
+            result ^= group[byte];
+        }
+        result
+    }
+}
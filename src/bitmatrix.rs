@@ -0,0 +1,280 @@
+//! Matrix of bits (Galois(2)) of any size
+//!
+//! This is like `BitColumnMatrix`, but each column is held in as many u64 blocks as needed, so
+//! the dimensions are not limited by the width of a machine word. That allows state transition
+//! matrices for generators with states of hundreds of bits, such as xoshiro256 or WELL512, or the
+//! combined states of the LFSR generators.
+//!
+//! The matrices can be rectangular, with `ROWS` not equal to `COLS`. A `ROWS`×`COLS` matrix maps
+//! a vector of `COLS` bits to a vector of `ROWS` bits, and multiplication composes the maps. Eg
+//! the 32-bit output of a generator can be expressed as a `BitMatrix<32, N>` over its N-bit
+//! state, such as the xor of the four LFSR113 components over a 128-bit state.
+//!
+//! A vector of bits is a slice of u64 blocks, least significant first. That is, bit `i` of the
+//! vector is bit `i % 64` of block `i / 64`.
+
+use num_traits::{ConstOne, ConstZero, One, Pow, PrimInt, Unsigned, Zero};
+use std::ops::BitAnd;
+
+const BLOCK_BITS: usize = 64;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BitMatrix<const ROWS: usize, const COLS: usize> {
+    /// Columns, each of `BLOCKS` blocks, one after the other.
+    blocks: Vec<u64>,
+}
+
+impl<const ROWS: usize, const COLS: usize> BitMatrix<ROWS, COLS> {
+    /// Number of u64 blocks in each column, and in a result vector.
+    pub const BLOCKS: usize = ROWS.div_ceil(BLOCK_BITS);
+    /// Number of u64 blocks in an input vector.
+    pub const INPUT_BLOCKS: usize = COLS.div_ceil(BLOCK_BITS);
+
+    /// Create a matrix from its columns' blocks, one column after the other.
+    ///
+    /// # Panics
+    ///
+    /// If the length of `init_data` isn't `COLS * BLOCKS`.
+    pub fn new(init_data: &[u64]) -> BitMatrix<ROWS, COLS> {
+        assert_eq!(
+            init_data.len(),
+            COLS * Self::BLOCKS,
+            "BitMatrix data length mismatch"
+        );
+        let mut result = BitMatrix::<ROWS, COLS> {
+            blocks: init_data.to_vec(),
+        };
+        result.mask_columns();
+        result
+    }
+
+    /// Create the matrix of a function that is linear over Galois(2).
+    ///
+    /// Column i is `f` applied to the unit vector with only bit i set. `f` is given `INPUT_BLOCKS`
+    /// blocks, and must return `BLOCKS` blocks.
+    pub fn from_linear_fn(f: impl Fn(&[u64]) -> Vec<u64>) -> BitMatrix<ROWS, COLS> {
+        let mut blocks = Vec::with_capacity(COLS * Self::BLOCKS);
+        let mut unit = vec![0_u64; Self::INPUT_BLOCKS];
+        for i in 0..COLS {
+            unit[i / BLOCK_BITS] = 1 << (i % BLOCK_BITS);
+            let column = f(&unit);
+            assert_eq!(
+                column.len(),
+                Self::BLOCKS,
+                "BitMatrix function result length mismatch"
+            );
+            blocks.extend_from_slice(&column);
+            unit[i / BLOCK_BITS] = 0;
+        }
+        BitMatrix::new(&blocks)
+    }
+
+    /// Clear any bits beyond `ROWS` in the last block of each column.
+    // `is_multiple_of()` needs Rust 1.87.
+    #[allow(clippy::manual_is_multiple_of)]
+    fn mask_columns(&mut self) {
+        if ROWS % BLOCK_BITS != 0 {
+            let mask = (1_u64 << (ROWS % BLOCK_BITS)) - 1;
+            for column in self.blocks.chunks_mut(Self::BLOCKS) {
+                column[Self::BLOCKS - 1] &= mask;
+            }
+        }
+    }
+
+    /// The blocks of column `c`.
+    pub fn column(&self, c: usize) -> &[u64] {
+        &self.blocks[c * Self::BLOCKS..(c + 1) * Self::BLOCKS]
+    }
+
+    /// The element at row `r`, column `c`.
+    pub fn get(&self, r: usize, c: usize) -> bool {
+        (self.column(c)[r / BLOCK_BITS] >> (r % BLOCK_BITS)) & 1 != 0
+    }
+
+    /// Set the element at row `r`, column `c`.
+    pub fn set(&mut self, r: usize, c: usize, value: bool) {
+        let block = &mut self.blocks[c * Self::BLOCKS + r / BLOCK_BITS];
+        let bit = 1 << (r % BLOCK_BITS);
+        if value {
+            *block |= bit;
+        } else {
+            *block &= !bit;
+        }
+    }
+
+    /// Matrix multiplication, which is composition of the linear maps.
+    ///
+    /// The matrices can be rectangular. Eg an output function `BitMatrix<32, 128>` composed with
+    /// a state transition `BitMatrix<128, 128>` gives the output after one step as a function of
+    /// the original state.
+    pub fn dot<const K: usize>(&self, b: &BitMatrix<COLS, K>) -> BitMatrix<ROWS, K> {
+        let mut blocks = Vec::with_capacity(K * Self::BLOCKS);
+        for c in 0..K {
+            blocks.extend_from_slice(&self.dot_vec(b.column(c)));
+        }
+        BitMatrix::<ROWS, K> { blocks }
+    }
+
+    /// Transpose of the matrix.
+    pub fn transpose(&self) -> BitMatrix<COLS, ROWS> {
+        let mut result = BitMatrix::<COLS, ROWS>::zero();
+        for c in 0..COLS {
+            for r in 0..ROWS {
+                if self.get(r, c) {
+                    result.set(c, r, true);
+                }
+            }
+        }
+        result
+    }
+
+    /// Rank of the matrix, by Gaussian elimination.
+    pub fn rank(&self) -> usize {
+        let mut columns: Vec<Vec<u64>> = self
+            .blocks
+            .chunks(Self::BLOCKS)
+            .map(|c| c.to_vec())
+            .collect();
+        let mut rank = 0;
+        for r in 0..ROWS {
+            let (block, bit) = (r / BLOCK_BITS, 1 << (r % BLOCK_BITS));
+            if let Some(pivot) = (rank..COLS).find(|&c| columns[c][block] & bit != 0) {
+                columns.swap(rank, pivot);
+                let (done, rest) = columns.split_at_mut(rank + 1);
+                let pivot_column = &done[rank];
+                for column in rest.iter_mut() {
+                    if column[block] & bit != 0 {
+                        for (a, b) in column.iter_mut().zip(pivot_column.iter()) {
+                            *a ^= *b;
+                        }
+                    }
+                }
+                rank += 1;
+            }
+        }
+        rank
+    }
+
+    /// Multiply the matrix by a vector of `COLS` bits, giving a vector of `ROWS` bits.
+    pub fn dot_vec(&self, b: &[u64]) -> Vec<u64> {
+        let mut result = vec![0_u64; Self::BLOCKS];
+        for (i, b_block) in b.iter().enumerate().take(Self::INPUT_BLOCKS) {
+            let mut b_temp = *b_block;
+            while b_temp != 0 {
+                let c = i * BLOCK_BITS + b_temp.trailing_zeros() as usize;
+                if c >= COLS {
+                    break;
+                }
+                for (r, a) in result.iter_mut().zip(self.column(c)) {
+                    *r ^= *a;
+                }
+                b_temp &= b_temp - 1;
+            }
+        }
+        result
+    }
+}
+
+impl<const WIDTH: usize> BitMatrix<WIDTH, WIDTH> {
+    pub fn dot_equ(&mut self, b: &BitMatrix<WIDTH, WIDTH>) {
+        *self = self.dot(b);
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Zero for BitMatrix<ROWS, COLS> {
+    /// Create a zero-matrix.
+    fn zero() -> BitMatrix<ROWS, COLS> {
+        BitMatrix::<ROWS, COLS> {
+            blocks: vec![0; COLS * Self::BLOCKS],
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.blocks.iter().all(|block| *block == 0)
+    }
+}
+
+impl<const WIDTH: usize> One for BitMatrix<WIDTH, WIDTH> {
+    /// Create a unity-matrix. That is, ones on the diagonal, zeros elsewhere.
+    fn one() -> BitMatrix<WIDTH, WIDTH> {
+        let mut result = BitMatrix::<WIDTH, WIDTH>::zero();
+        for i in 0..WIDTH {
+            result.set(i, i, true);
+        }
+        result
+    }
+}
+
+impl<N, const WIDTH: usize> Pow<N> for BitMatrix<WIDTH, WIDTH>
+where
+    N: Unsigned + PrimInt + BitAnd + ConstOne + ConstZero,
+{
+    type Output = Self;
+
+    /// Raise a matrix to a power. Efficient matrix exponentiation.
+    fn pow(self, n: N) -> BitMatrix<WIDTH, WIDTH> {
+        let mut result = BitMatrix::<WIDTH, WIDTH>::one();
+        let mut temp_exp = self;
+        let mut n_work: N = n;
+
+        loop {
+            if n_work & N::ONE != N::ZERO {
+                result.dot_equ(&temp_exp);
+            }
+            n_work = n_work >> 1;
+            if n_work == N::ZERO {
+                break;
+            }
+            temp_exp = temp_exp.dot(&temp_exp);
+        }
+        result
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> core::ops::Add for BitMatrix<ROWS, COLS> {
+    type Output = Self;
+
+    /// Add two matrices.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, b: BitMatrix<ROWS, COLS>) -> BitMatrix<ROWS, COLS> {
+        &self + &b
+    }
+}
+
+impl<'b, const ROWS: usize, const COLS: usize> core::ops::Add<&'b BitMatrix<ROWS, COLS>>
+    for &BitMatrix<ROWS, COLS>
+{
+    type Output = BitMatrix<ROWS, COLS>;
+
+    /// Add two matrices (by reference).
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, b: &'b BitMatrix<ROWS, COLS>) -> BitMatrix<ROWS, COLS> {
+        let mut result = self.clone();
+        for (r, b_block) in result.blocks.iter_mut().zip(b.blocks.iter()) {
+            *r ^= *b_block;
+        }
+        result
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, const K: usize> core::ops::Mul<BitMatrix<COLS, K>>
+    for BitMatrix<ROWS, COLS>
+{
+    type Output = BitMatrix<ROWS, K>;
+
+    /// Multiply two matrices.
+    fn mul(self, b: BitMatrix<COLS, K>) -> BitMatrix<ROWS, K> {
+        self.dot(&b)
+    }
+}
+
+impl<'b, const ROWS: usize, const COLS: usize, const K: usize>
+    core::ops::Mul<&'b BitMatrix<COLS, K>> for &BitMatrix<ROWS, COLS>
+{
+    type Output = BitMatrix<ROWS, K>;
+
+    /// Multiply two matrices (by reference).
+    fn mul(self, b: &'b BitMatrix<COLS, K>) -> BitMatrix<ROWS, K> {
+        self.dot(b)
+    }
+}
@@ -0,0 +1,409 @@
+//! Cycle finding, and exhaustive period checks on small-word generators
+//!
+//! The periods of the generators are predicted by theory: 2^32 for `Cong` by the Hull–Dobell
+//! theorem, 2^32-1 for `SHR3` since its characteristic polynomial is primitive, and the order of
+//! 2^16 modulo a prime for each half of `MWC1`. This module checks such predictions by machine.
+//!
+//! * [`brent`] finds the cycle reached from one state, by Brent's algorithm. It takes time
+//!   proportional to the cycle length, so a 2^32 cycle is feasible, but slow.
+//! * [`permutation_cycles`] finds the complete cycle structure of a permutation of a small state
+//!   space, by visiting every state.
+//! * [`linear_cycles`] predicts the cycle structure of a linear map over Galois(2), from the ranks
+//!   of powers of its matrix, without visiting the states.
+//!
+//! Small-word analogues of the generators, [`XorshiftTriple`] and [`SmallMwc`], have state spaces
+//! small enough to check the predicted cycle structure exhaustively. That also shows the flaw of
+//! the shift triple (17, 13, 5) in Marsaglia's 1999 definition of SHR3 [[mars1]](crate#mars1)
+//! [[rose1]](crate#rose1): it has 64 cycles rather than one of length 2^32-1.
+//!
+//! Checks of the full 32-bit generators take minutes, so those tests are only built with the
+//! `slow-tests` feature, eg `cargo test --release --features slow-tests`.
+
+// `is_multiple_of()` needs Rust 1.87.
+#![allow(clippy::manual_is_multiple_of)]
+
+use crate::bitcolumnmatrix::BitColumnMatrix;
+use crate::gf2poly::Gf2Poly;
+use crate::maths;
+use crate::MERSENNE_FACTORS;
+use num_traits::{One, Pow};
+use std::collections::BTreeMap;
+
+/// Cycle structure: the number of cycles of each length, keyed by the cycle length.
+pub type CycleStructure = BTreeMap<u128, u128>;
+
+/// Cycle reached from a starting state
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Cycle {
+    /// Number of steps before the cycle is entered. It is 0 if the starting state is on the cycle.
+    pub tail: u128,
+    /// Length of the cycle.
+    pub length: u128,
+}
+
+/// Find the cycle reached by repeatedly applying `f` to `x0`, by Brent's algorithm.
+///
+/// It takes about 2 to 3 times as many steps as the tail plus the cycle length, and constant
+/// memory.
+///
+///     use simplerandom::cycles;
+///     // Multiplication by 2 modulo 11 has order 10.
+///     let cycle = cycles::brent(1_u32, |x| x * 2 % 11);
+///     assert_eq!(cycle, cycles::Cycle { tail: 0, length: 10 });
+pub fn brent<T, F>(x0: T, mut f: F) -> Cycle
+where
+    T: Copy + Eq,
+    F: FnMut(T) -> T,
+{
+    // Find the cycle length, with the tortoise teleporting to the hare at each power of 2.
+    let mut power = 1_u128;
+    let mut length = 1_u128;
+    let mut tortoise = x0;
+    let mut hare = f(x0);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare;
+            power *= 2;
+            length = 0;
+        }
+        hare = f(hare);
+        length += 1;
+    }
+
+    // Find the start of the cycle, with the hare kept one cycle length ahead.
+    let mut tortoise = x0;
+    let mut hare = x0;
+    for _ in 0..length {
+        hare = f(hare);
+    }
+    let mut tail = 0_u128;
+    while tortoise != hare {
+        tortoise = f(tortoise);
+        hare = f(hare);
+        tail += 1;
+    }
+    Cycle { tail, length }
+}
+
+/// Find the cycle structure of a permutation `f` of the states 0 to `size`-1, by visiting every
+/// state.
+///
+/// It needs one bit of memory per state.
+///
+/// # Panics
+///
+/// If `f` is found not to be a permutation of the states.
+pub fn permutation_cycles<F>(size: u64, mut f: F) -> CycleStructure
+where
+    F: FnMut(u64) -> u64,
+{
+    let mut visited = vec![0_u64; size.div_ceil(64) as usize];
+    let mut structure = CycleStructure::new();
+    for start in 0..size {
+        if test_and_set(&mut visited, start) {
+            continue;
+        }
+        let mut length = 1_u128;
+        let mut x = f(start);
+        while x != start {
+            assert!(
+                x < size && !test_and_set(&mut visited, x),
+                "function is not a permutation of the states"
+            );
+            x = f(x);
+            length += 1;
+        }
+        *structure.entry(length).or_insert(0) += 1;
+    }
+    structure
+}
+
+/// Set bit `x` of a bitmap, returning whether it was already set.
+fn test_and_set(bitmap: &mut [u64], x: u64) -> bool {
+    let (word, bit) = ((x / 64) as usize, 1 << (x % 64));
+    let result = bitmap[word] & bit != 0;
+    bitmap[word] |= bit;
+    result
+}
+
+/// Predict the cycle structure of the linear map over Galois(2) with the given matrix.
+///
+/// Every state x on a cycle has a period that divides some L, which is found from the degrees of
+/// the irreducible factors of the characteristic polynomial. The number of states with
+/// T^d x = x is 2^(WIDTH - rank(T^d + I)), for each divisor d of L. Then the number of states with
+/// period exactly d follows by Möbius inversion.
+///
+/// The zero state is counted as a cycle of length 1. If the matrix is singular, states that are
+/// not on a cycle are not counted.
+///
+///     use simplerandom::bitcolumnmatrix::BitColumnMatrix;
+///     use simplerandom::cycles;
+///     // SHR3: the zero state, and one cycle of all the other states.
+///     let matrix = BitColumnMatrix::<u32, 32>::from_linear_fn(|mut x| {
+///         x ^= x << 13;
+///         x ^= x >> 17;
+///         x ^= x << 5;
+///         x
+///     });
+///     let structure = cycles::linear_cycles(&matrix);
+///     assert_eq!(structure, [(1, 1), (0xFFFF_FFFF, 1)].iter().copied().collect());
+///
+/// # Panics
+///
+/// If WIDTH is more than 64.
+pub fn linear_cycles<T, const WIDTH: usize>(matrix: &BitColumnMatrix<T, WIDTH>) -> CycleStructure
+where
+    T: crate::bitcolumnmatrix::BitColumnMatrixInt,
+{
+    assert!(WIDTH <= 64, "matrix is too large");
+    let char_poly = matrix.char_poly();
+
+    // Distinct-degree factorisation: gcd(x^(2^d) - x, p) is the product of the distinct
+    // irreducible factors of p whose degree divides d.
+    let mut factor_counts = vec![0_usize; WIDTH + 1];
+    let mut odd_order = 1_u128;
+    let mut primes: Vec<u128> = Vec::new();
+    let mut x_power = Gf2Poly::x().rem(&char_poly);
+    for d in 1..=WIDTH {
+        x_power = x_power.square().rem(&char_poly);
+        let g = (x_power.clone() + Gf2Poly::x()).gcd(&char_poly);
+        let smaller: usize = (1..d)
+            .filter(|e| d % *e == 0)
+            .map(|e| e * factor_counts[e])
+            .sum();
+        factor_counts[d] = (g.degree().unwrap_or(0) - smaller) / d;
+        if factor_counts[d] > 0 {
+            let mersenne = (1_u128 << d) - 1;
+            odd_order = odd_order / maths::gcd(odd_order, mersenne) * mersenne;
+            for &f in MERSENNE_FACTORS[d - 1] {
+                if !primes.contains(&f) {
+                    primes.push(f);
+                }
+            }
+        }
+    }
+
+    // Repeated factors give periods with a factor of 2, up to the smallest power of 2 that is at
+    // least WIDTH.
+    let mut fixed_points_cache = BTreeMap::new();
+    let mut fixed_points = |d: u128| -> i128 {
+        *fixed_points_cache.entry(d).or_insert_with(|| {
+            let rank = (matrix.clone().pow(d) + BitColumnMatrix::one()).rank();
+            1_i128 << (WIDTH - rank)
+        })
+    };
+    let max_two_power = WIDTH.next_power_of_two().trailing_zeros();
+    let periodic = fixed_points(odd_order << max_two_power);
+    let two_power = (0..=max_two_power)
+        .find(|&s| fixed_points(odd_order << s) == periodic)
+        .unwrap_or(max_two_power);
+    let order = odd_order << two_power;
+    if two_power > 0 {
+        primes.push(2);
+    }
+
+    let mut structure = CycleStructure::new();
+    for d in divisors(order, &primes) {
+        // Inclusion–exclusion over the distinct primes dividing d.
+        let d_primes: Vec<u128> = primes
+            .iter()
+            .copied()
+            .filter(|p| d % *p == 0)
+            .collect();
+        let mut exact = 0_i128;
+        for subset in 0..(1_usize << d_primes.len()) {
+            let divisor: u128 = d_primes
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| subset & (1 << i) != 0)
+                .map(|(_, p)| p)
+                .product();
+            let sign = if subset.count_ones() % 2 == 0 { 1 } else { -1 };
+            exact += sign * fixed_points(d / divisor);
+        }
+        if exact > 0 {
+            structure.insert(d, exact as u128 / d);
+        }
+    }
+    structure
+}
+
+/// All divisors of `n`, whose distinct prime factors are `primes`.
+fn divisors(n: u128, primes: &[u128]) -> Vec<u128> {
+    let mut result = vec![1_u128];
+    for &p in primes {
+        let mut power_count = 0;
+        let mut m = n;
+        while m % p == 0 {
+            m /= p;
+            power_count += 1;
+        }
+        let previous = result.clone();
+        let mut power = 1_u128;
+        for _ in 0..power_count {
+            power *= p;
+            result.extend(previous.iter().map(|d| d * power));
+        }
+    }
+    result.sort_unstable();
+    result
+}
+
+/* XorshiftTriple ------------------------------------------------------------*/
+
+/// Xorshift generator on BITS-bit words, with any shift triple
+///
+/// y[n]=y[n-1](I+L^a)(I+R^b)(I+L^c),
+/// as for SHR3 and `Xorshift16`. With BITS of 16 or less, the whole state space can be checked.
+///
+///     use simplerandom::cycles::XorshiftTriple;
+///     // The triple of Xorshift16 has a single cycle of all non-zero states.
+///     let xorshift = XorshiftTriple::<16>::new(7, 9, 8);
+///     assert_eq!(xorshift.cycles(), xorshift.predicted_cycles());
+///     assert_eq!(xorshift.cycles(), [(1, 1), (0xFFFF, 1)].iter().copied().collect());
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct XorshiftTriple<const BITS: usize> {
+    a: u32,
+    b: u32,
+    c: u32,
+}
+
+impl<const BITS: usize> XorshiftTriple<BITS> {
+    const MASK: u64 = if BITS >= 64 {
+        u64::MAX
+    } else {
+        (1 << BITS) - 1
+    };
+
+    /// # Panics
+    ///
+    /// If BITS is more than 64, or a shift is not between 1 and BITS-1.
+    pub fn new(a: u32, b: u32, c: u32) -> XorshiftTriple<BITS> {
+        assert!(BITS <= 64, "word size is too large");
+        for shift in [a, b, c] {
+            assert!(0 < shift && (shift as usize) < BITS, "invalid shift");
+        }
+        XorshiftTriple { a, b, c }
+    }
+
+    /// Next state after `x`.
+    pub fn step(&self, x: u64) -> u64 {
+        let mut x = x & Self::MASK;
+        x ^= (x << self.a) & Self::MASK;
+        x ^= x >> self.b;
+        x ^= (x << self.c) & Self::MASK;
+        x
+    }
+
+    /// Transition matrix of the generator.
+    pub fn matrix(&self) -> BitColumnMatrix<u64, BITS> {
+        BitColumnMatrix::from_linear_fn(|x| self.step(x))
+    }
+
+    /// Cycle structure found by visiting all 2^BITS states.
+    ///
+    /// # Panics
+    ///
+    /// If BITS is 64.
+    pub fn cycles(&self) -> CycleStructure {
+        assert!(BITS < 64, "state space is too large");
+        permutation_cycles(1 << BITS, |x| self.step(x))
+    }
+
+    /// Cycle structure predicted from the transition matrix.
+    pub fn predicted_cycles(&self) -> CycleStructure {
+        linear_cycles(&self.matrix())
+    }
+}
+
+/* SmallMwc ------------------------------------------------------------------*/
+
+/// Lag-1 multiply-with-carry generator in base 2^`bits`, with multiplier `a`
+///
+/// The state s = c 2^bits + x combines the value x and the carry c, and the step is
+/// s[n] = a x[n-1] + c[n-1], as for `MWC8` and each half of `MWC1`. The states are 0 to
+/// m = a 2^bits - 1, and the step is equivalent to multiplying by the inverse of 2^bits modulo m.
+/// So for 0 < s < m, the period of s is the order of 2^bits modulo m/gcd(s, m), while 0 and m are
+/// fixed points. When m is prime, all the other states have the same period.
+///
+///     use simplerandom::cycles::SmallMwc;
+///     // MWC8: m = 63743 is prime, and the order of 2^8 is (m-1)/2.
+///     let mwc = SmallMwc::new(8, 249);
+///     assert_eq!(mwc.cycles(), mwc.predicted_cycles());
+///     assert_eq!(mwc.cycles(), [(1, 2), (31871, 2)].iter().copied().collect());
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SmallMwc {
+    bits: u32,
+    a: u64,
+}
+
+impl SmallMwc {
+    /// # Panics
+    ///
+    /// If the modulus a 2^bits - 1 doesn't fit in 63 bits, or a is less than 2.
+    pub fn new(bits: u32, a: u64) -> SmallMwc {
+        assert!(a >= 2, "invalid multiplier");
+        assert!(
+            bits + (64 - a.leading_zeros()) <= 63,
+            "modulus is too large"
+        );
+        SmallMwc { bits, a }
+    }
+
+    /// Modulus m = a 2^bits - 1.
+    pub fn modulus(&self) -> u64 {
+        (self.a << self.bits) - 1
+    }
+
+    /// Next state after `s`.
+    pub fn step(&self, s: u64) -> u64 {
+        self.a * (s & ((1 << self.bits) - 1)) + (s >> self.bits)
+    }
+
+    /// Cycle structure found by visiting all m+1 states.
+    pub fn cycles(&self) -> CycleStructure {
+        permutation_cycles(self.modulus() + 1, |s| self.step(s))
+    }
+
+    /// Cycle structure predicted from the modulus.
+    ///
+    /// For each divisor n > 1 of m, the φ(n) states with m/gcd(s, m) = n form cycles of length
+    /// equal to the order of 2^bits modulo n. The order is found by counting, so this is only
+    /// suitable for small moduli.
+    pub fn predicted_cycles(&self) -> CycleStructure {
+        let m = self.modulus() as u128;
+        let mut primes = Vec::new();
+        let mut rest = m;
+        let mut p = 2;
+        while p * p <= rest {
+            if rest % p == 0 {
+                primes.push(p);
+                while rest % p == 0 {
+                    rest /= p;
+                }
+            }
+            p += 1;
+        }
+        if rest > 1 {
+            primes.push(rest);
+        }
+
+        let mut structure = CycleStructure::new();
+        structure.insert(1, 2);
+        for n in divisors(m, &primes).into_iter().filter(|&n| n > 1) {
+            let totient = primes
+                .iter()
+                .filter(|&&p| n % p == 0)
+                .fold(n, |t, &p| t / p * (p - 1));
+            let base = (1_u128 << self.bits) % n;
+            let mut order = 1;
+            let mut power = base;
+            while power != 1 {
+                power = power * base % n;
+                order += 1;
+            }
+            *structure.entry(order).or_insert(0) += totient / order;
+        }
+        structure
+    }
+}
@@ -0,0 +1,168 @@
+//! Equidistribution analysis of linear generators over Galois(2)
+//!
+//! A generator with a k-bit state is (t, l)-equidistributed if, over all states in its period,
+//! every combination of the most significant l bits of t successive outputs occurs equally often.
+//! For a linear generator, that is the case if the linear map from the state to those t×l bits
+//! has rank t×l. Since there are only k bits of state, t×l can be at most k. The equidistribution
+//! dimension t_l for resolution l is the largest such t, and the generator is maximally
+//! equidistributed (ME) if t_l = floor(k/l) for every resolution l.
+//!
+//! L'Ecuyer chose the parameters of the combined LFSR generators `LFSR88` and `LFSR113` to be ME
+//! [[lecuyer1]](crate#lecuyer1) [[lecuyer2]](crate#lecuyer2). The ranks are calculated here with
+//! the generator's transition and output maps as `BitMatrix` values, so the claim can be checked.
+
+use crate::bitmatrix::BitMatrix;
+use crate::TauswortheComponent32;
+use num_traits::Pow;
+
+/// Resolution-wise equidistribution of a generator
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Equidistribution {
+    /// Dimension of the state space of the recurrence.
+    k: usize,
+    /// Equidistribution dimension t_l for each resolution l, starting from l = 1.
+    dimensions: Vec<usize>,
+}
+
+/// Set of linearly independent rows, in echelon form.
+struct RowBasis {
+    /// Each row, with the position of its leading bit.
+    rows: Vec<(usize, Vec<u64>)>,
+}
+
+impl RowBasis {
+    fn new() -> RowBasis {
+        RowBasis { rows: Vec::new() }
+    }
+
+    /// Add a row to the basis, returning `false` if it is linearly dependent on the rows already
+    /// there.
+    ///
+    /// The row is reduced by the basis rows in the order they were added. Each basis row was
+    /// reduced by the rows before it, so it doesn't reintroduce their leading bits.
+    fn insert(&mut self, mut row: Vec<u64>) -> bool {
+        for (lead, basis_row) in self.rows.iter() {
+            if (row[lead / 64] >> (lead % 64)) & 1 != 0 {
+                for (a, b) in row.iter_mut().zip(basis_row.iter()) {
+                    *a ^= *b;
+                }
+            }
+        }
+        match row.iter().enumerate().rev().find(|(_, block)| **block != 0) {
+            Some((i, block)) => {
+                let lead = i * 64 + 63 - block.leading_zeros() as usize;
+                self.rows.push((lead, row));
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Equidistribution {
+    /// Analyse a linear generator with an N-bit state and W-bit output.
+    ///
+    /// `transition` is the generator's state transition, and `output` is the output as a function
+    /// of the state after a step. The state can have bits that are not part of the recurrence,
+    /// such as the least significant bits of each LFSR component. So the analysis is done on the
+    /// steady-state subspace, the image of `transition`^N, whose dimension is k.
+    pub fn new<const N: usize, const W: usize>(
+        transition: &BitMatrix<N, N>,
+        output: &BitMatrix<W, N>,
+    ) -> Equidistribution {
+        let steady = transition.clone().pow(N as u32);
+        let k = steady.rank();
+
+        // Output after i steps from the steady state, as row vectors over the state.
+        let mut outputs_t: Vec<BitMatrix<N, W>> = Vec::with_capacity(k);
+        let mut output_i = output.dot(&steady);
+        for _ in 0..k {
+            outputs_t.push(output_i.transpose());
+            output_i = output_i.dot(transition);
+        }
+
+        let mut dimensions = Vec::with_capacity(W);
+        for l in 1..=W {
+            let mut basis = RowBasis::new();
+            let mut t = 0;
+            'outputs: while t < k / l {
+                // Rows of the l most significant output bits.
+                for r in W - l..W {
+                    if !basis.insert(outputs_t[t].column(r).to_vec()) {
+                        break 'outputs;
+                    }
+                }
+                t += 1;
+            }
+            dimensions.push(t);
+        }
+        Equidistribution { k, dimensions }
+    }
+
+    /// Analyse a combined Tausworthe generator, whose output is the xor of the components.
+    ///
+    /// N must be 32 times the number of components.
+    ///
+    /// # Panics
+    ///
+    /// If N doesn't match the number of components.
+    pub fn combined_tausworthe<const N: usize>(
+        components: &[TauswortheComponent32],
+    ) -> Equidistribution {
+        assert_eq!(
+            N,
+            32 * components.len(),
+            "state size doesn't match the components"
+        );
+        let word = |v: &[u64], i: usize| (v[i / 2] >> (32 * (i % 2))) as u32;
+        let transition = BitMatrix::<N, N>::from_linear_fn(|v| {
+            let mut result = vec![0_u64; N.div_ceil(64)];
+            for (i, component) in components.iter().enumerate() {
+                result[i / 2] |= (component.matrix().dot_vec(word(v, i)) as u64) << (32 * (i % 2));
+            }
+            result
+        });
+        let output = BitMatrix::<32, N>::from_linear_fn(|v| {
+            let z = (0..components.len()).fold(0, |z, i| z ^ word(v, i));
+            vec![z as u64]
+        });
+        Equidistribution::new(&transition, &output)
+    }
+
+    /// Dimension k of the state space of the recurrence.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Equidistribution dimension t_l, for resolution `l` from 1 to the output width.
+    ///
+    /// # Panics
+    ///
+    /// If `l` is 0 or more than the output width.
+    pub fn dimension(&self, l: usize) -> usize {
+        assert!(
+            (1..=self.dimensions.len()).contains(&l),
+            "resolution must be from 1 to the output width"
+        );
+        self.dimensions[l - 1]
+    }
+
+    /// Dimension gap floor(k/l) - t_l, for resolution `l` from 1 to the output width.
+    ///
+    /// # Panics
+    ///
+    /// If `l` is 0 or more than the output width.
+    pub fn gap(&self, l: usize) -> usize {
+        self.k / l - self.dimension(l)
+    }
+
+    /// Sum of the dimension gaps over all resolutions.
+    pub fn total_gap(&self) -> usize {
+        (1..=self.dimensions.len()).map(|l| self.gap(l)).sum()
+    }
+
+    /// Whether the generator is maximally equidistributed, with no dimension gaps.
+    pub fn is_maximally_equidistributed(&self) -> bool {
+        self.total_gap() == 0
+    }
+}
@@ -0,0 +1,362 @@
+//! Polynomials over Galois(2)
+//!
+//! The coefficients of the polynomial are represented by the bits of a
+//! vector of u64, least significant coefficient first. That is, bit `i` of the
+//! polynomial is the coefficient of x^i. The polynomial can be of any degree,
+//! which is needed for the characteristic polynomials of large-state
+//! generators such as the Mersenne Twister (degree 19937).
+
+use num_traits::Zero;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Gf2Poly {
+    /// Coefficients, least significant first. There are never any trailing zero words, so that a
+    /// given polynomial has a unique representation.
+    words: Vec<u64>,
+}
+
+const WORD_BITS: usize = 64;
+
+/// Spread the 32 bits of `x` to the even bit positions of a u64.
+fn spread_bits(x: u32) -> u64 {
+    let mut x = x as u64;
+    x = (x | (x << 16)) & 0x0000FFFF0000FFFF;
+    x = (x | (x << 8)) & 0x00FF00FF00FF00FF;
+    x = (x | (x << 4)) & 0x0F0F0F0F0F0F0F0F;
+    x = (x | (x << 2)) & 0x3333333333333333;
+    x = (x | (x << 1)) & 0x5555555555555555;
+    x
+}
+
+/// Xor `src`, shifted left by `shift` bits, into `dest`.
+///
+/// `dest` is extended if necessary.
+fn xor_shifted(dest: &mut Vec<u64>, src: &[u64], shift: usize) {
+    let word_shift = shift / WORD_BITS;
+    let bit_shift = shift % WORD_BITS;
+    let len = src.len() + word_shift + 1;
+    if dest.len() < len {
+        dest.resize(len, 0);
+    }
+    if bit_shift == 0 {
+        for (d, s) in dest[word_shift..].iter_mut().zip(src.iter()) {
+            *d ^= *s;
+        }
+    } else {
+        for (i, s) in src.iter().enumerate() {
+            dest[word_shift + i] ^= *s << bit_shift;
+            dest[word_shift + i + 1] ^= *s >> (WORD_BITS - bit_shift);
+        }
+    }
+}
+
+impl Gf2Poly {
+    /// Create a polynomial from coefficient words, least significant first.
+    pub fn from_words(words: &[u64]) -> Gf2Poly {
+        let mut result = Gf2Poly {
+            words: words.to_vec(),
+        };
+        result.trim();
+        result
+    }
+
+    /// The polynomial `x`.
+    pub fn x() -> Gf2Poly {
+        Gf2Poly { words: vec![2] }
+    }
+
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    fn trim(&mut self) {
+        while let Some(&0) = self.words.last() {
+            self.words.pop();
+        }
+    }
+
+    /// Degree of the polynomial, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.words.last().map(|&top| {
+            (self.words.len() - 1) * WORD_BITS + (WORD_BITS - 1 - top.leading_zeros() as usize)
+        })
+    }
+
+    /// Coefficient of x^i.
+    pub fn coefficient(&self, i: usize) -> bool {
+        match self.words.get(i / WORD_BITS) {
+            Some(word) => (word >> (i % WORD_BITS)) & 1 != 0,
+            None => false,
+        }
+    }
+
+    /// Multiply by x^`n`.
+    pub fn shl(&self, n: usize) -> Gf2Poly {
+        let mut words = Vec::new();
+        xor_shifted(&mut words, &self.words, n);
+        Gf2Poly::from_words(&words)
+    }
+
+    /// Divide by x^`n`, discarding the remainder.
+    pub fn shr(&self, n: usize) -> Gf2Poly {
+        let word_shift = n / WORD_BITS;
+        let bit_shift = n % WORD_BITS;
+        if word_shift >= self.words.len() {
+            return Gf2Poly::zero();
+        }
+        let src = &self.words[word_shift..];
+        let mut words = vec![0_u64; src.len()];
+        for i in 0..src.len() {
+            words[i] = src[i] >> bit_shift;
+            if bit_shift != 0 && i + 1 < src.len() {
+                words[i] |= src[i + 1] << (WORD_BITS - bit_shift);
+            }
+        }
+        Gf2Poly::from_words(&words)
+    }
+
+    /// Square of the polynomial.
+    ///
+    /// Over Galois(2), the square of a polynomial just spreads the coefficients to the even powers
+    /// of x, so this is much faster than a general multiplication.
+    pub fn square(&self) -> Gf2Poly {
+        let mut words = Vec::with_capacity(self.words.len() * 2);
+        for word in self.words.iter() {
+            words.push(spread_bits(*word as u32));
+            words.push(spread_bits((*word >> 32) as u32));
+        }
+        Gf2Poly::from_words(&words)
+    }
+
+    /// Product of two polynomials.
+    pub fn mul(&self, b: &Gf2Poly) -> Gf2Poly {
+        let mut words = Vec::new();
+        for (i, word) in self.words.iter().enumerate() {
+            let mut word_temp = *word;
+            while word_temp != 0 {
+                let bit = word_temp.trailing_zeros() as usize;
+                xor_shifted(&mut words, &b.words, i * WORD_BITS + bit);
+                word_temp &= word_temp - 1;
+            }
+        }
+        Gf2Poly::from_words(&words)
+    }
+
+    /// Remainder of division by polynomial `m`.
+    ///
+    /// # Panics
+    ///
+    /// If `m` is zero.
+    pub fn rem(&self, m: &Gf2Poly) -> Gf2Poly {
+        let m_degree = m.degree().expect("division by zero polynomial");
+        let mut words = self.words.clone();
+        let mut i = self.words.len() * WORD_BITS;
+        while i > m_degree {
+            i -= 1;
+            if (words[i / WORD_BITS] >> (i % WORD_BITS)) & 1 != 0 {
+                xor_shifted(&mut words, &m.words, i - m_degree);
+            }
+        }
+        Gf2Poly::from_words(&words)
+    }
+
+    /// Product of two polynomials, modulo polynomial `m`.
+    pub fn mul_mod(&self, b: &Gf2Poly, m: &Gf2Poly) -> Gf2Poly {
+        self.mul(b).rem(m)
+    }
+
+    /// Raise the polynomial to the power `n`, modulo polynomial `m`.
+    ///
+    /// This is done by left-to-right binary exponentiation. Each square and each multiplication by
+    /// the base is reduced modulo `m`, so the intermediate results stay of lower degree than `m`.
+    pub fn pow_mod(&self, n: u128, m: &Gf2Poly) -> Gf2Poly {
+        let base = self.rem(m);
+        let mut result = Gf2Poly::from_words(&[1]).rem(m);
+        let mut bit = 128 - n.leading_zeros();
+        while bit > 0 {
+            bit -= 1;
+            result = result.square().rem(m);
+            if (n >> bit) & 1 != 0 {
+                result = result.mul_mod(&base, m);
+            }
+        }
+        result
+    }
+
+    /// Raise the polynomial to the power `n`.
+    pub fn pow(&self, n: u32) -> Gf2Poly {
+        let mut result = Gf2Poly::from_words(&[1]);
+        let mut bit = 32 - n.leading_zeros();
+        while bit > 0 {
+            bit -= 1;
+            result = result.square();
+            if (n >> bit) & 1 != 0 {
+                result = result.mul(self);
+            }
+        }
+        result
+    }
+
+    /// Greatest common divisor of two polynomials, by Euclid's algorithm.
+    pub fn gcd(&self, b: &Gf2Poly) -> Gf2Poly {
+        let mut a = self.clone();
+        let mut b = b.clone();
+        while !b.is_zero() {
+            let r = a.rem(&b);
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    /// Check whether the polynomial is primitive.
+    ///
+    /// `factors` must be the distinct prime factors of 2^k-1, where k is the degree of the
+    /// polynomial. The polynomial is primitive if x has multiplicative order 2^k-1 modulo the
+    /// polynomial. That is, x^(2^k-1) = 1, but x^((2^k-1)/f) != 1 for each prime factor f. A
+    /// linear generator whose characteristic polynomial is primitive has the maximal period 2^k-1.
+    ///
+    /// # Panics
+    ///
+    /// If the degree is greater than 127.
+    pub fn is_primitive(&self, factors: &[u128]) -> bool {
+        let k = match self.degree() {
+            Some(k) if k > 0 => k,
+            _ => return false,
+        };
+        assert!(k < 128, "primitivity test is limited to degree 127");
+        let order = (1_u128 << k) - 1;
+        let one = Gf2Poly::from_words(&[1]);
+        Gf2Poly::x().pow_mod(order, self) == one
+            && factors.iter().all(|f| Gf2Poly::x().pow_mod(order / *f, self) != one)
+    }
+
+    /// Inverse of the polynomial, modulo polynomial `m`.
+    ///
+    /// This is calculated by the extended Euclidean algorithm. The result is `None` if the
+    /// polynomial and `m` are not coprime, so the inverse doesn't exist.
+    pub fn inverse_mod(&self, m: &Gf2Poly) -> Option<Gf2Poly> {
+        // Invariants: r0 = s0 * self (mod m), r1 = s1 * self (mod m).
+        let mut r0 = m.clone();
+        let mut s0 = Gf2Poly::zero();
+        let mut r1 = self.rem(m);
+        let mut s1 = Gf2Poly::from_words(&[1]);
+        while let Some(r1_degree) = r1.degree() {
+            while let Some(r0_degree) = r0.degree() {
+                if r0_degree < r1_degree {
+                    break;
+                }
+                let shift = r0_degree - r1_degree;
+                r0.xor_shifted_assign(&r1, shift);
+                s0.xor_shifted_assign(&s1, shift);
+            }
+            std::mem::swap(&mut r0, &mut r1);
+            std::mem::swap(&mut s0, &mut s1);
+        }
+        if r0.degree() == Some(0) {
+            Some(s0.rem(m))
+        } else {
+            None
+        }
+    }
+
+    /// Add `b` multiplied by x^`shift`, in place.
+    fn xor_shifted_assign(&mut self, b: &Gf2Poly, shift: usize) {
+        xor_shifted(&mut self.words, &b.words, shift);
+        self.trim();
+    }
+
+    /// Minimal polynomial of a bit sequence, by the Berlekamp–Massey algorithm.
+    ///
+    /// The result is the monic polynomial p(x) = x^L + c1 x^(L-1) + ... + cL of lowest degree L,
+    /// such that the sequence satisfies the linear recurrence
+    /// s[n] = c1 s[n-1] + c2 s[n-2] + ... + cL s[n-L]
+    /// (with addition modulo 2). To determine a polynomial of degree L, at least 2L bits of the
+    /// sequence are needed.
+    ///
+    /// If the bits are a linear function of the state of a linear generator, then this is a
+    /// factor of the characteristic polynomial of the generator's transition matrix. It is
+    /// typically equal to it.
+    pub fn minimal_polynomial(bits: &[bool]) -> Gf2Poly {
+        let n = bits.len();
+        // Pack the sequence in reverse order, so the terms of the discrepancy calculation are
+        // contiguous bits in the same order as the connection polynomial's coefficients.
+        let mut reversed = vec![0_u64; n / WORD_BITS + 2];
+        for (i, bit) in bits.iter().enumerate() {
+            if *bit {
+                let j = n - 1 - i;
+                reversed[j / WORD_BITS] |= 1 << (j % WORD_BITS);
+            }
+        }
+
+        let mut c = vec![1_u64];
+        let mut b = vec![1_u64];
+        let mut length = 0_usize;
+        let mut shift = 1_usize;
+        for i in 0..n {
+            // Discrepancy: s[i] + c1 s[i-1] + ... + cL s[i-L].
+            let offset = n - 1 - i;
+            let word_shift = offset / WORD_BITS;
+            let bit_shift = offset % WORD_BITS;
+            let mut discrepancy = 0_u64;
+            for (k, c_word) in c.iter().enumerate() {
+                let lo = reversed.get(word_shift + k).copied().unwrap_or(0);
+                let hi = reversed.get(word_shift + k + 1).copied().unwrap_or(0);
+                let s_word = if bit_shift == 0 {
+                    lo
+                } else {
+                    (lo >> bit_shift) | (hi << (WORD_BITS - bit_shift))
+                };
+                discrepancy ^= c_word & s_word;
+            }
+            if discrepancy.count_ones() & 1 == 0 {
+                shift += 1;
+            } else if 2 * length <= i {
+                let c_prev = c.clone();
+                xor_shifted(&mut c, &b, shift);
+                length = i + 1 - length;
+                b = c_prev;
+                shift = 1;
+            } else {
+                xor_shifted(&mut c, &b, shift);
+                shift += 1;
+            }
+            // Keep the connection polynomial no longer than needed.
+            c.truncate(length / WORD_BITS + 1);
+        }
+
+        // The connection polynomial C(x) = 1 + c1 x + ... + cL x^L is the reciprocal of the
+        // minimal polynomial.
+        let mut words = vec![0_u64; length / WORD_BITS + 1];
+        for j in 0..=length {
+            if (c.get(j / WORD_BITS).copied().unwrap_or(0) >> (j % WORD_BITS)) & 1 != 0 {
+                let k = length - j;
+                words[k / WORD_BITS] |= 1 << (k % WORD_BITS);
+            }
+        }
+        Gf2Poly::from_words(&words)
+    }
+}
+
+impl Zero for Gf2Poly {
+    /// Create a zero polynomial.
+    fn zero() -> Gf2Poly {
+        Gf2Poly { words: Vec::new() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl core::ops::Add for Gf2Poly {
+    type Output = Self;
+
+    /// Add two polynomials.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, b: Gf2Poly) -> Gf2Poly {
+        let mut words = self.words;
+        xor_shifted(&mut words, &b.words, 0);
+        Gf2Poly::from_words(&words)
+    }
+}
@@ -11,6 +11,8 @@
 //! * Safe seeding. Many generators have some "bad" state values that must be avoided. The seed
 //!   functions for all generators ensure that any "bad" state values are avoided, and replaced by
 //!   a suitable alternative initial state.
+//! * Single-value seeding. Generators that take multiple seed values can also be seeded from one
+//!   64-bit value, which is expanded by [`SplitMix64`] in a documented way.
 //! * These random number generators have been implemented in the following languages:
 //!     * C
 //!     * Python
@@ -25,6 +27,8 @@
 //! * Implement target language's API idioms and/or existing random number generator API.
 //! * Reasonable statistical properties of pseudo-random output (though not for all generators
 //!   provided).
+//! * A small built-in battery of statistical tests, in the [`stats`] module, to smoke-test any
+//!   `RngCore` generator without external test suites.
 //!
 //! ## Algorithms
 //!
@@ -58,6 +62,28 @@
 //! | [`KISS2`]   | Combination of MWC64, Cong and SHR3. From [[mars2]](#mars2).
 //! | [`LFSR113`] | Combined LFSR (Tausworthe) random number generator by L'Ecuyer. From [[lecuyer1]](#lecuyer1) [[lecuyer3]](#lecuyer3).
 //! | [`LFSR88`]  | Combined LFSR (Tausworthe) random number generator by L'Ecuyer. From [[lecuyer2]](#lecuyer2).
+//! | [`PCG32`]   | Permuted congruential generator (XSH-RR variant) by O'Neill, with selectable stream. From [[oneill1]](#oneill1).
+//! | [`Lcg`]     | Generic linear congruential generator, with a power of two or prime modulus. `Cong` is an instance of it.
+//! | [`MINSTD`]  | Park and Miller "minimal standard" multiplicative congruential generator, the same as C++ `std::minstd_rand0`.
+//! | [`GLIBC`]   | Congruential generator of the glibc `rand()` function, `TYPE_0` form.
+//! | [`MMIX`]    | Knuth's 64-bit congruential generator for MMIX.
+//! | [`MRG32k3a`]| Combined multiple recursive generator by L'Ecuyer, as used in RngStreams. From [[lecuyer4]](#lecuyer4).
+//! | [`MT19937`] | Mersenne Twister by Matsumoto and Nishimura, the same as C++ `std::mt19937`. From [[matsumoto1]](#matsumoto1).
+//! | [`MT19937_64`]| 64-bit Mersenne Twister, the same as C++ `std::mt19937_64`. From [[matsumoto1]](#matsumoto1).
+//! | [`WELL512a`]| "Well Equidistributed Long-period Linear" generator by Panneton, L'Ecuyer and Matsumoto. From [[panneton1]](#panneton1).
+//! | [`WELL1024a`]| Larger-state WELL generator, by Panneton, L'Ecuyer and Matsumoto. From [[panneton1]](#panneton1).
+//! | [`SplitMix64`]| Weyl sequence with a mixing function, by Steele, Lea and Flood. Also used for seed expansion. From [[steele1]](#steele1).
+//! | [`Philox4x32`]| Counter-based generator Philox4x32-10, from the Random123 library. From [[salmon1]](#salmon1).
+//! | [`Threefry2x32`]| Counter-based generator Threefry2x32-20, from the Random123 library. From [[salmon1]](#salmon1).
+//! | [`MWC256`]  | Lag-256 multiply-with-carry, a preset of the general lag-r [`Mwc`]. From [[mars3]](#mars3).
+//! | [`CMWC4096`]| Lag-4096 complementary multiply-with-carry, a preset of the general lag-r [`Mwc`]. From [[mars3]](#mars3).
+//! | [`SubtractWithBorrow`]| Subtract-with-borrow, the same as C++ `std::subtract_with_carry_engine` (`Ranlux24Base`, `Ranlux48Base`). From [[marzam1]](#marzam1).
+//! | [`Ranlux`]  | Lüscher's RANLUX, with configurable luxury level. `Ranlux24` and `Ranlux48` are the same as C++ `std::ranlux24` and `std::ranlux48`. From [[luscher1]](#luscher1).
+//! | [`Cong16`]  | 16-bit congruential generator, with only 16-bit arithmetic, for 8- and 16-bit microcontrollers.
+//! | [`Xorshift16`]| 16-bit 3-shift-register generator, with only 16-bit arithmetic, for 8- and 16-bit microcontrollers.
+//! | [`MWC8`]    | Multiply-with-carry in base 2^8, with only an 8 by 8 bit multiply, for 8- and 16-bit microcontrollers.
+//! | [`Combined`]| Generic combination of any generators, by wrapping addition or exclusive-or, in the way of KISS.
+//! | [`TauswortheComponent`]| Single LFSR (Tausworthe) component with parameters (k, q, s), checked for maximal period, for assembling combined LFSR generators. From [[lecuyer1]](#lecuyer1) [[lecuyer2]](#lecuyer2).
 //!
 //! ## License
 //!
@@ -93,20 +119,355 @@
 //! <a name="lecuyer3">\[lecuyer3\]</a>  
 //! [LFSR113 C double implementation](http://www.iro.umontreal.ca/~simardr/rng/lfsr113.c)  
 //! Pierre L'Ecuyer
+//!
+//! <a name="oneill1">\[oneill1\]</a>  
+//! [PCG: A Family of Simple Fast Space-Efficient Statistically Good Algorithms for Random Number Generation](https://www.pcg-random.org/paper.html)  
+//! Melissa E. O'Neill  
+//! Harvey Mudd College, HMC-CS-2014-0905 (2014).
+//!
+//! <a name="lecuyer4">\[lecuyer4\]</a>  
+//! [Good Parameters and Implementations for Combined Multiple Recursive Random Number Generators](https://pubsonline.informs.org/doi/10.1287/opre.47.1.159)  
+//! P. L'Ecuyer  
+//! Operations Research, 47, 1 (1999), 159–164.
+//!
+//! <a name="matsumoto1">\[matsumoto1\]</a>  
+//! [Mersenne Twister: A 623-Dimensionally Equidistributed Uniform Pseudo-Random Number Generator](http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/ARTICLES/mt.pdf)  
+//! Makoto Matsumoto, Takuji Nishimura  
+//! ACM Transactions on Modeling and Computer Simulation, 8, 1 (1998), 3–30.
+//!
+//! <a name="haramoto1">\[haramoto1\]</a>  
+//! [Efficient Jump Ahead for F2-Linear Random Number Generators](http://www.iro.umontreal.ca/~lecuyer/myftp/papers/jumpf2.pdf)  
+//! H. Haramoto, M. Matsumoto, T. Nishimura, F. Panneton, P. L'Ecuyer  
+//! INFORMS Journal on Computing, 20, 3 (2008), 385–390.
+//!
+//! <a name="panneton1">\[panneton1\]</a>  
+//! [Improved Long-Period Generators Based on Linear Recurrences Modulo 2](http://www.iro.umontreal.ca/~lecuyer/myftp/papers/wellrng.pdf)  
+//! F. Panneton, P. L'Ecuyer, M. Matsumoto  
+//! ACM Transactions on Mathematical Software, 32, 1 (2006), 1–16.
+//!
+//! <a name="steele1">\[steele1\]</a>  
+//! [Fast Splittable Pseudorandom Number Generators](https://doi.org/10.1145/2714064.2660195)  
+//! Guy L. Steele Jr., Doug Lea, Christine H. Flood  
+//! OOPSLA 2014, ACM SIGPLAN Notices, 49, 10 (2014), 453–472.
+//!
+//! <a name="salmon1">\[salmon1\]</a>  
+//! [Parallel Random Numbers: As Easy as 1, 2, 3](https://doi.org/10.1145/2063384.2063405)  
+//! John K. Salmon, Mark A. Moraes, Ron O. Dror, David E. Shaw  
+//! SC '11: Proceedings of the 2011 International Conference for High Performance Computing, Networking, Storage and Analysis.
+//!
+//! <a name="mars3">\[mars3\]</a>  
+//! [Random Number Generators](https://doi.org/10.22237/jmasm/1051747320)  
+//! George Marsaglia  
+//! Journal of Modern Applied Statistical Methods, 2, 1 (2003), 2–13.
+//!
+//! <a name="marzam1">\[marzam1\]</a>  
+//! [A New Class of Random Number Generators](https://doi.org/10.1214/aoap/1177005878)  
+//! George Marsaglia, Arif Zaman  
+//! The Annals of Applied Probability, 1, 3 (1991), 462–480.
+//!
+//! <a name="tezuka1">\[tezuka1\]</a>  
+//! On the Lattice Structure of the Add-with-Carry and Subtract-with-Borrow Random Number Generators  
+//! Shu Tezuka, Pierre L'Ecuyer, Raymond Couture  
+//! ACM Transactions on Modeling and Computer Simulation, 3, 4 (1993), 315–331.
+//!
+//! <a name="luscher1">\[luscher1\]</a>  
+//! [A portable high-quality random number generator for lattice field theory simulations](https://doi.org/10.1016/0010-4655(94)90232-1)  
+//! Martin Lüscher  
+//! Computer Physics Communications, 79, 1 (1994), 100–110.
+//!
+//! <a name="lecuyer5">\[lecuyer5\]</a>  
+//! [TestU01: A C Library for Empirical Testing of Random Number Generators](https://doi.org/10.1145/1268776.1268777)  
+//! Pierre L'Ecuyer, Richard Simard  
+//! ACM Transactions on Mathematical Software, 33, 4 (2007), Article 22.
+//!
+//! <a name="nist1">\[nist1\]</a>  
+//! [A Statistical Test Suite for Random and Pseudorandom Number Generators for Cryptographic Applications](https://doi.org/10.6028/NIST.SP.800-22r1a)  
+//! Andrew Rukhin et al.  
+//! NIST Special Publication 800-22 Revision 1a (2010).
 
 use rand_core::{RngCore, Error, impls};
-use num_traits::{PrimInt, Unsigned, WrappingAdd, WrappingMul, Pow};
-use std::ops::SubAssign;
+use num_traits::{NumCast, PrimInt, Unsigned, WrappingAdd, WrappingMul, Pow};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use biguint::BigUint;
+use num_traits::Zero;
+use std::sync::OnceLock;
+use std::ops::{AddAssign, MulAssign, SubAssign};
 
 pub mod maths;
 pub mod bitcolumnmatrix;
+pub mod bitmatrix;
+pub mod cycles;
+pub mod equidistribution;
+pub mod stats;
+pub mod modmatrix;
+pub mod gf2poly;
+pub mod biguint;
 
 pub trait RngJumpAhead {
     fn jumpahead<N>(&mut self, n: N)
         where N: maths::IntTypes;
 }
 
+/// The period of a generator, for any seed.
+///
+/// The period can be much larger than any primitive integer type, so it is a [`BigUint`]. It is
+/// provided for the generators whose period is known exactly, with a simple expression.
+pub trait Period {
+    fn period() -> BigUint;
+}
+
+type BitColumnMatrix16 = bitcolumnmatrix::BitColumnMatrix::<u16, 16>;
 type BitColumnMatrix32 = bitcolumnmatrix::BitColumnMatrix::<u32, 32>;
+type ModMatrix3 = modmatrix::ModMatrix::<u32, 3>;
+
+/// Concatenate `word_bits`-bit words, first word most significant, to make at least `bits` bits.
+///
+/// The caller keeps the lower bits it needs.
+fn concat_words<F>(word_bits: u32, bits: u32, mut next_word: F) -> u64
+    where F: FnMut() -> u64
+{
+    let mut result = 0_u64;
+    let mut result_bits = 0;
+    while result_bits < bits {
+        result = (result << word_bits) | next_word();
+        result_bits += word_bits;
+    }
+    result
+}
+
+
+/* Lcg -----------------------------------------------------------------------*/
+
+/// Shorthand for traits needed in `Lcg`.
+pub trait LcgInt: maths::UIntTypes + WrappingMul + AddAssign + MulAssign + NumCast + Debug {}
+impl<T: maths::UIntTypes + WrappingMul + AddAssign + MulAssign + NumCast + Debug> LcgInt for T {}
+
+/// Lcg -- Linear congruential random number generator
+///
+/// This is a generic linear congruential generator:
+/// x[n]=(M x[n-1]+C) mod MOD.
+///
+/// The modulus `MOD` selects one of two modes of calculation:
+///
+/// * Power of two. If `MOD` is 0, the modulus is 2^bits of the integer type `T`, that is, the
+///   natural wrapping of `T`. If `MOD` is a power of two smaller than that, the state is masked to
+///   the lower bits. In this mode, the period is at most `MOD`.
+/// * Prime. Any other value of `MOD` must be a prime that fits in `T`. The calculation is done with
+///   [`maths::mul_mod`], so `MOD` may be close to the maximum value of `T`. In this mode, `M` must
+///   not be 1, and the period divides `MOD`-1.
+///
+/// The integer type `T` can be `u8` to `u64`. `M` and `C` must be less than the modulus, and `M`
+/// must not be 0. These are checked when the type is built, so invalid parameters fail to compile.
+///
+/// Seeding reduces the seed modulo `MOD`. For a multiplicative generator (`C` = 0), a zero state is
+/// replaced by `MOD`-1, since zero would stay at zero forever.
+///
+/// The output of `next_u32()` is based on the new state value, which is in the range [0..MOD).
+/// For a power of two modulus 2^k, it depends on the state width k:
+///
+/// * More than 32 bits: the upper 32 bits of the state are output, since the upper bits of an LCG
+///   are much more random than the lower bits.
+/// * 32 bits or fewer: the state is output as-is. This matches C generators such as `GLIBC`.
+///   However, for a state type narrower than 32 bits with the natural wrapping modulus (`MOD` 0),
+///   the states of as many steps as are needed are concatenated, first step most significant.
+///
+/// For a prime modulus, the lower bits are as random as the upper bits. The state is output as-is
+/// if it fits, otherwise its lower 32 bits are output. A `u16` state also has `next_u16()` and
+/// `next_u8()`.
+///
+/// Jumpahead is done with time order `O(log n)` for both modes. For the power of two mode, it uses
+/// [`maths::wrapping_pow`] and [`maths::wrapping_geom_series`]. For the prime mode, it uses
+/// [`maths::pow_mod`] and [`maths::geom_series_mod`].
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     // The C++ `std::minstd_rand0`
+///     let mut s = simplerandom::Lcg::<u32, 16807, 0, 2147483647>::new(1);
+///     let r = s.next_u32();
+///     assert_eq!(r, 16807);
+///     let r = s.next_u32();
+///     assert_eq!(r, 282475249);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1622650073);
+///     let r = s.next_u32();
+///     assert_eq!(r, 984943658);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u32();
+///     assert_eq!(r, 651011084);
+///
+/// A multiplier of 1 with a prime modulus fails to compile:
+///
+/// ```compile_fail
+/// let s = simplerandom::Lcg::<u32, 1, 12345, 2147483647>::new(1);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Lcg<T, const M: u64, const C: u64, const MOD: u64>
+    where T: LcgInt
+{
+    x: T,
+}
+
+impl<T, const M: u64, const C: u64, const MOD: u64> Lcg<T, M, C, MOD>
+    where T: LcgInt
+{
+    /// Check of the type parameters. It is evaluated when `new()` is instantiated, so invalid
+    /// parameters are a build error.
+    const VALID_PARAMETERS: () = {
+        let bits = maths::size_of_bits::<T>();
+        assert!(bits <= 64, "Lcg state type must be u8 to u64");
+        let t_max = (1_u128 << bits) - 1;
+        assert!(MOD as u128 <= t_max, "Lcg modulus doesn't fit the state type");
+        let modulus = if MOD == 0 { t_max + 1 } else { MOD as u128 };
+        assert!((M as u128) < modulus, "Lcg multiplier must be less than the modulus");
+        assert!((C as u128) < modulus, "Lcg increment must be less than the modulus");
+        assert!(M != 0, "Lcg multiplier must not be 0");
+        assert!(
+            MOD == 0 || MOD.is_power_of_two() || M != 1,
+            "Lcg multiplier must not be 1 for a prime modulus"
+        );
+    };
+
+    pub fn new(seed1: T) -> Lcg<T, M, C, MOD> {
+        let () = Lcg::<T, M, C, MOD>::VALID_PARAMETERS;
+        let mut lcg = Lcg {
+            x: seed1,
+        };
+        lcg.sanitise();
+        lcg
+    }
+    fn m() -> T {
+        NumCast::from(M).expect("Lcg multiplier doesn't fit the state type")
+    }
+    fn c() -> T {
+        NumCast::from(C).expect("Lcg increment doesn't fit the state type")
+    }
+    fn modulus() -> T {
+        NumCast::from(MOD).expect("Lcg modulus doesn't fit the state type")
+    }
+    fn is_power_of_two_mod() -> bool {
+        MOD == 0 || MOD.is_power_of_two()
+    }
+    /// Mask for the power of two mode.
+    fn mask() -> T {
+        if MOD == 0 {
+            T::max_value()
+        } else {
+            Lcg::<T, M, C, MOD>::modulus() - T::ONE
+        }
+    }
+    /// Number of bits of the state: k for a power of two modulus 2^k, otherwise the bit length of
+    /// the prime modulus.
+    fn state_bits() -> u32 {
+        if MOD == 0 {
+            maths::size_of_bits::<T>() as u32
+        } else if MOD.is_power_of_two() {
+            MOD.trailing_zeros()
+        } else {
+            64 - MOD.leading_zeros()
+        }
+    }
+    fn cycle_len() -> u128 {
+        if MOD == 0 {
+            1 << maths::size_of_bits::<T>()
+        } else {
+            MOD as u128
+        }
+    }
+    fn sanitise(&mut self) {
+        if Lcg::<T, M, C, MOD>::is_power_of_two_mod() {
+            self.x = self.x & Lcg::<T, M, C, MOD>::mask();
+            if C == 0 && self.x == T::ZERO {
+                self.x = Lcg::<T, M, C, MOD>::mask();
+            }
+        } else {
+            let modulus = Lcg::<T, M, C, MOD>::modulus();
+            self.x = self.x % modulus;
+            if C == 0 && self.x == T::ZERO {
+                self.x = modulus - T::ONE;
+            }
+        }
+    }
+    fn next_x(&mut self) {
+        let m = Lcg::<T, M, C, MOD>::m();
+        let c = Lcg::<T, M, C, MOD>::c();
+        if Lcg::<T, M, C, MOD>::is_power_of_two_mod() {
+            self.x = self.x.wrapping_mul(&m).wrapping_add(&c) & Lcg::<T, M, C, MOD>::mask();
+        } else {
+            let modulus = Lcg::<T, M, C, MOD>::modulus();
+            self.x = maths::add_mod(maths::mul_mod(m, self.x, modulus), c, modulus);
+        }
+    }
+    fn current(&self) -> u32 {
+        let state_bits = Lcg::<T, M, C, MOD>::state_bits();
+        if Lcg::<T, M, C, MOD>::is_power_of_two_mod() && state_bits > 32 {
+            (self.x >> (state_bits as usize - 32)).to_u32().unwrap_or(0)
+        } else {
+            // For a prime modulus, the lower bits are as good as the upper bits.
+            self.x.to_u64().unwrap_or(0) as u32
+        }
+    }
+}
+impl<const M: u64, const C: u64, const MOD: u64> Lcg<u16, M, C, MOD> {
+    /// Generate the next 16-bit value, which is the new state.
+    pub fn next_u16(&mut self) -> u16 {
+        self.next_x();
+        self.x
+    }
+    /// Generate the next 8-bit value, which is the upper 8 bits of the new state.
+    pub fn next_u8(&mut self) -> u8 {
+        (self.next_u16() >> 8) as u8
+    }
+}
+impl<T, const M: u64, const C: u64, const MOD: u64> RngCore for Lcg<T, M, C, MOD>
+    where T: LcgInt
+{
+    fn next_u32(&mut self) -> u32 {
+        let width_bits = maths::size_of_bits::<T>() as u32;
+        if MOD == 0 && width_bits < 32 {
+            concat_words(width_bits, 32, || {
+                self.next_x();
+                self.x.to_u64().unwrap_or(0)
+            }) as u32
+        } else {
+            self.next_x();
+            self.current()
+        }
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl<T, const M: u64, const C: u64, const MOD: u64> RngJumpAhead for Lcg<T, M, C, MOD>
+    where T: LcgInt
+{
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        let m = Lcg::<T, M, C, MOD>::m();
+        let c = Lcg::<T, M, C, MOD>::c();
+        if Lcg::<T, M, C, MOD>::is_power_of_two_mod() {
+            let n_mod = maths::modulo(n, Lcg::<T, M, C, MOD>::cycle_len());
+            let mult_exp = maths::wrapping_pow(m, n_mod);
+            let add_const = maths::wrapping_geom_series(m, n_mod).wrapping_mul(&c);
+            let x = mult_exp.wrapping_mul(&self.x).wrapping_add(&add_const);
+            self.x = x & Lcg::<T, M, C, MOD>::mask();
+        } else {
+            let modulus = Lcg::<T, M, C, MOD>::modulus();
+            let n_mod = maths::modulo(n, MOD - 1);
+            let mult_exp = maths::pow_mod(m, n_mod, modulus);
+            let add_const = maths::mul_mod(maths::geom_series_mod(m, n_mod, modulus), c, modulus);
+            self.x = maths::add_mod(maths::mul_mod(mult_exp, self.x, modulus), add_const, modulus);
+        }
+    }
+}
 
 
 /* Cong ----------------------------------------------------------------------*/
@@ -137,46 +498,103 @@ type BitColumnMatrix32 = bitcolumnmatrix::BitColumnMatrix::<u32, 32>;
 ///     s.jumpahead(1_000_000);
 ///     let r = s.next_u32();
 ///     assert_eq!(r, 2250891922);
-#[derive(Debug, Clone, Eq, PartialEq)]
-pub struct Cong {
-    cong: u32,
-}
+pub type Cong = Lcg<u32, 69069, 12345, 0>;
+
+/// MINSTD -- "Minimal standard" multiplicative congruential generator by Park and Miller
+///
+/// x[n]=16807x[n-1] mod (2^31-1).
+/// It has period 2^31-2. This is the same as the C++ `std::minstd_rand0`.
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::MINSTD::new(1);
+///     let r = s.next_u32();
+///     assert_eq!(r, 16807);
+///     s.jumpahead(9998);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1043618065);
+pub type MINSTD = Lcg<u32, 16807, 0, 2147483647>;
 
-impl Cong {
-    const M: u32 = 69069;
-    const C: u32 = 12345;
-    const CYCLE_LEN: u64 = 1 << 32;
+/// GLIBC -- Congruential generator of the glibc `rand()` function, in its simplest form
+///
+/// x[n]=(1103515245x[n-1]+12345) mod 2^31.
+/// It has period 2^31. This matches the glibc `TYPE_0` generator, selected by `initstate()` with an
+/// 8-byte state. The output is the full 31-bit state, so the lower bits are very regular.
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::GLIBC::new(1);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1103527590);
+///     let r = s.next_u32();
+///     assert_eq!(r, 377401575);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u32();
+///     assert_eq!(r, 2141981524);
+pub type GLIBC = Lcg<u32, 1103515245, 12345, 0x80000000>;
 
-    pub fn new(seed1: u32) -> Cong {
-        Cong {
-            cong: seed1,
-        }
+/// MMIX -- Knuth's 64-bit congruential generator for MMIX
+///
+/// x[n]=6364136223846793005x[n-1]+1442695040888963407 mod 2^64.
+/// It has period 2^64. The output is the upper 32 bits of the state.
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::MMIX::new(1);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1817669548);
+///     let r = s.next_u32();
+///     assert_eq!(r, 2187888307);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1185760222);
+pub type MMIX = Lcg<u64, 6364136223846793005, 1442695040888963407, 0>;
+
+/// Cong16 -- 16-bit congruential generator, for 8- and 16-bit microcontrollers
+///
+/// x[n]=25173x[n-1]+13849 mod 2^16.
+/// It has period 2^16 for any seed. The calculation only needs a 16-bit multiply. `next_u16()`
+/// outputs the state, and `next_u8()` its upper 8 bits. As with any power-of-two LCG, the lower
+/// bits are very regular. `next_u32()` concatenates the states of two steps.
+///
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::Cong16::new(1);
+///     let r = s.next_u16();
+///     assert_eq!(r, 39022);
+///     let r = s.next_u16();
+///     assert_eq!(r, 61087);
+///     let r = s.next_u8();
+///     assert_eq!(r, 78);
+///     let r = s.next_u8();
+///     assert_eq!(r, 175);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u16();
+///     assert_eq!(r, 29418);
+pub type Cong16 = Lcg<u16, 25173, 13849, 0>;
+
+impl Period for Cong {
+    fn period() -> BigUint {
+        BigUint::from_u128(Cong::cycle_len())
     }
 }
-impl RngCore for Cong {
-    fn next_u32(&mut self) -> u32 {
-        self.cong = self.cong.wrapping_mul(Cong::M).wrapping_add(Cong::C);
-        self.cong
-    }
-    fn next_u64(&mut self) -> u64 {
-        impls::next_u64_via_u32(self)
+impl Period for MINSTD {
+    fn period() -> BigUint {
+        BigUint::from_u64(MINSTD::modulus() as u64 - 1)
     }
-    fn fill_bytes(&mut self, dest: &mut [u8]) {
-        impls::fill_bytes_via_next(self, dest)
+}
+impl Period for GLIBC {
+    fn period() -> BigUint {
+        BigUint::from_u128(GLIBC::cycle_len())
     }
-    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+}
+impl Period for MMIX {
+    fn period() -> BigUint {
+        BigUint::from_u128(MMIX::cycle_len())
     }
 }
-impl RngJumpAhead for Cong {
-    fn jumpahead<N>(&mut self, n: N)
-        where N: maths::IntTypes
-    {
-        let n_mod = maths::modulo(n, Cong::CYCLE_LEN);
-        let mult_exp = maths::wrapping_pow(Cong::M, n_mod);
-        let add_const = maths::wrapping_geom_series(Cong::M, n_mod).wrapping_mul(Cong::C);
-        let cong = mult_exp.wrapping_mul(self.cong).wrapping_add(add_const);
-        self.cong = cong;
+impl Period for Cong16 {
+    fn period() -> BigUint {
+        BigUint::from_u128(Cong16::cycle_len())
     }
 }
 
@@ -232,18 +650,40 @@ impl SHR3 {
             self.shr3 = 0xFFFFFFFF;
         }
     }
+    /// Transition matrix of the step, for jumpahead.
+    fn matrix() -> &'static BitColumnMatrix32 {
+        static MATRIX: OnceLock<BitColumnMatrix32> = OnceLock::new();
+        MATRIX.get_or_init(|| {
+            BitColumnMatrix32::from_linear_fn(|shr3| {
+                let mut s = SHR3 { shr3 };
+                s.step();
+                s.shr3
+            })
+        })
+    }
 }
-impl RngCore for SHR3 {
-    fn next_u32(&mut self) -> u32 {
-        self.sanitise();
+impl LinearStep for SHR3 {
+    fn zero_state() -> SHR3 {
+        SHR3::new(0)
+    }
+    fn step(&mut self) {
         let mut shr3 = self.shr3;
 
         shr3 ^= shr3 << 13;
         shr3 ^= shr3 >> 17;
         shr3 ^= shr3 << 5;
         self.shr3 = shr3;
+    }
+    fn add_state(&mut self, other: &SHR3) {
+        self.shr3 ^= other.shr3;
+    }
+}
+impl RngCore for SHR3 {
+    fn next_u32(&mut self) -> u32 {
+        self.sanitise();
+        self.step();
 
-        shr3
+        self.shr3
     }
     fn next_u64(&mut self) -> u64 {
         impls::next_u64_via_u32(self)
@@ -252,26 +692,25 @@ impl RngCore for SHR3 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for SHR3 {
     fn jumpahead<N>(&mut self, n: N)
         where N: maths::IntTypes
     {
-        const SHR3_MATRIX_ARRAY: [u32; 32] = [
-            0x00042021, 0x00084042, 0x00108084, 0x00210108, 0x00420231, 0x00840462, 0x010808C4, 0x02101188,
-            0x04202310, 0x08404620, 0x10808C40, 0x21011880, 0x42023100, 0x84046200, 0x0808C400, 0x10118800,
-            0x20231000, 0x40462021, 0x808C4042, 0x01080084, 0x02100108, 0x04200210, 0x08400420, 0x10800840,
-            0x21001080, 0x42002100, 0x84004200, 0x08008400, 0x10010800, 0x20021000, 0x40042000, 0x80084000,
-        ];
         let n_mod = maths::modulo(n, SHR3::CYCLE_LEN);
         self.sanitise();
-        let shr3_matrix = BitColumnMatrix32::new(&SHR3_MATRIX_ARRAY);
-        let shr3_mult = shr3_matrix.pow(n_mod);
+        let shr3_mult = SHR3::matrix().clone().pow(n_mod);
         self.shr3 = shr3_mult.dot_vec(self.shr3);
     }
 }
+impl Period for SHR3 {
+    fn period() -> BigUint {
+        BigUint::from_u64(SHR3::CYCLE_LEN as u64)
+    }
+}
 
 
 /* MWC2 ----------------------------------------------------------------------*/
@@ -343,6 +782,13 @@ impl MWC2 {
             lower: seed2,
         }
     }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// See [`SplitMix64`] for details of the seed expansion.
+    pub fn from_u64_seed(seed: u64) -> MWC2 {
+        let mut sm = SplitMix64::new(seed);
+        MWC2::new(sm.next_u32(), sm.next_u32())
+    }
     fn sanitise(&mut self) {
         self.upper = mwc_sanitise(self.upper, MWC2::UPPER_MOD);
         self.lower = mwc_sanitise(self.lower, MWC2::LOWER_MOD);
@@ -366,7 +812,8 @@ impl RngCore for MWC2 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for MWC2 {
@@ -381,6 +828,11 @@ impl RngJumpAhead for MWC2 {
         self.lower = maths::mul_mod(maths::pow_mod(MWC2::LOWER_M, n_lower, MWC2::LOWER_MOD), self.lower, MWC2::LOWER_MOD);
     }
 }
+impl Period for MWC2 {
+    fn period() -> BigUint {
+        BigUint::from_u64(MWC2::UPPER_CYCLE_LEN as u64).lcm(&BigUint::from_u64(MWC2::LOWER_CYCLE_LEN as u64))
+    }
+}
 
 
 /* MWC1 ----------------------------------------------------------------------*/
@@ -428,6 +880,13 @@ impl MWC1 {
             mwc: MWC2::new(seed1, seed2),
         }
     }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// See [`SplitMix64`] for details of the seed expansion.
+    pub fn from_u64_seed(seed: u64) -> MWC1 {
+        let mut sm = SplitMix64::new(seed);
+        MWC1::new(sm.next_u32(), sm.next_u32())
+    }
     fn current(&self) -> u32 {
         self.mwc.lower.wrapping_add(self.mwc.upper << 16)
     }
@@ -444,7 +903,8 @@ impl RngCore for MWC1 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for MWC1 {
@@ -454,6 +914,11 @@ impl RngJumpAhead for MWC1 {
         self.mwc.jumpahead(n);
     }
 }
+impl Period for MWC1 {
+    fn period() -> BigUint {
+        MWC2::period()
+    }
+}
 
 
 /* KISS ----------------------------------------------------------------------*/
@@ -496,8 +961,15 @@ impl KISS {
             shr3: SHR3::new(seed4),
         }
     }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// See [`SplitMix64`] for details of the seed expansion.
+    pub fn from_u64_seed(seed: u64) -> KISS {
+        let mut sm = SplitMix64::new(seed);
+        KISS::new(sm.next_u32(), sm.next_u32(), sm.next_u32(), sm.next_u32())
+    }
     fn current(&self) -> u32 {
-        (self.mwc.current() ^ self.cong.cong).wrapping_add(self.shr3.shr3)
+        (self.mwc.current() ^ self.cong.x).wrapping_add(self.shr3.shr3)
     }
 }
 impl RngCore for KISS {
@@ -515,7 +987,8 @@ impl RngCore for KISS {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for KISS {
@@ -527,6 +1000,11 @@ impl RngJumpAhead for KISS {
         self.shr3.jumpahead(n);
     }
 }
+impl Period for KISS {
+    fn period() -> BigUint {
+        MWC2::period().lcm(&Cong::period()).lcm(&SHR3::period())
+    }
+}
 
 
 /* MWC64 ---------------------------------------------------------------------*/
@@ -567,6 +1045,13 @@ impl MWC64 {
             mwc: (((seed1 as u64) << 32) ^ (seed2 as u64)),
         }
     }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// See [`SplitMix64`] for details of the seed expansion.
+    pub fn from_u64_seed(seed: u64) -> MWC64 {
+        let mut sm = SplitMix64::new(seed);
+        MWC64::new(sm.next_u32(), sm.next_u32())
+    }
     fn sanitise(&mut self) {
         self.mwc = mwc_sanitise(self.mwc, MWC64::MOD);
     }
@@ -592,7 +1077,8 @@ impl RngCore for MWC64 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for MWC64 {
@@ -604,6 +1090,11 @@ impl RngJumpAhead for MWC64 {
         self.mwc = maths::mul_mod(maths::pow_mod(MWC64::M, n_mod, MWC64::MOD), self.mwc, MWC64::MOD);
     }
 }
+impl Period for MWC64 {
+    fn period() -> BigUint {
+        BigUint::from_u64(MWC64::CYCLE_LEN)
+    }
+}
 
 
 /* KISS2 ---------------------------------------------------------------------*/
@@ -645,8 +1136,15 @@ impl KISS2 {
             shr3: SHR3::new(seed4),
         }
     }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// See [`SplitMix64`] for details of the seed expansion.
+    pub fn from_u64_seed(seed: u64) -> KISS2 {
+        let mut sm = SplitMix64::new(seed);
+        KISS2::new(sm.next_u32(), sm.next_u32(), sm.next_u32(), sm.next_u32())
+    }
     fn current(&self) -> u32 {
-        self.mwc.current().wrapping_add(self.cong.cong).wrapping_add(self.shr3.shr3)
+        self.mwc.current().wrapping_add(self.cong.x).wrapping_add(self.shr3.shr3)
     }
 }
 impl RngCore for KISS2 {
@@ -664,7 +1162,8 @@ impl RngCore for KISS2 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for KISS2 {
@@ -676,6 +1175,11 @@ impl RngJumpAhead for KISS2 {
         self.shr3.jumpahead(n);
     }
 }
+impl Period for KISS2 {
+    fn period() -> BigUint {
+        MWC64::period().lcm(&Cong::period()).lcm(&SHR3::period())
+    }
+}
 
 
 /* LFSR ----------------------------------------------------------------------*/
@@ -747,6 +1251,13 @@ impl LFSR88 {
             z3: lfsr_seed_z(seed3),
         }
     }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// See [`SplitMix64`] for details of the seed expansion.
+    pub fn from_u64_seed(seed: u64) -> LFSR88 {
+        let mut sm = SplitMix64::new(seed);
+        LFSR88::new(sm.next_u32(), sm.next_u32(), sm.next_u32())
+    }
     fn sanitise_z1(&mut self) {
         self.z1 = lfsr_sanitise_z(self.z1, LFSR88::Z1_MIN);
     }
@@ -768,6 +1279,25 @@ impl LFSR88 {
     fn current(&self) -> u32 {
         self.z1 ^ self.z2 ^ self.z3
     }
+    /// Transition matrices of the components' steps, for jumpahead.
+    fn matrices() -> &'static [BitColumnMatrix32; 3] {
+        static MATRICES: OnceLock<[BitColumnMatrix32; 3]> = OnceLock::new();
+        MATRICES.get_or_init(|| {
+            // Each component steps independently of the others.
+            let step = |z: u32| {
+                let mut s = LFSR88 { z1: z, z2: z, z3: z };
+                s.next_z1();
+                s.next_z2();
+                s.next_z3();
+                s
+            };
+            [
+                BitColumnMatrix32::from_linear_fn(|z| step(z).z1),
+                BitColumnMatrix32::from_linear_fn(|z| step(z).z2),
+                BitColumnMatrix32::from_linear_fn(|z| step(z).z3),
+            ]
+        })
+    }
 }
 impl RngCore for LFSR88 {
     fn next_u32(&mut self) -> u32 {
@@ -787,51 +1317,37 @@ impl RngCore for LFSR88 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for LFSR88 {
     fn jumpahead<N>(&mut self, n: N)
         where N: maths::IntTypes
     {
-        const LFSR88_Z1_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00002000, 0x00004000, 0x00008000, 0x00010000, 0x00020000, 0x00040001, 0x00080002,
-            0x00100004, 0x00200008, 0x00400010, 0x00800020, 0x01000040, 0x02000080, 0x04000100, 0x08000200,
-            0x10000400, 0x20000800, 0x40001000, 0x80000001, 0x00000002, 0x00000004, 0x00000008, 0x00000010,
-            0x00000020, 0x00000040, 0x00000080, 0x00000100, 0x00000200, 0x00000400, 0x00000800, 0x00001000
-        ];
-        const LFSR88_Z2_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00000000, 0x00000000, 0x00000080, 0x00000100, 0x00000200, 0x00000400, 0x00000800,
-            0x00001000, 0x00002000, 0x00004000, 0x00008000, 0x00010000, 0x00020000, 0x00040000, 0x00080000,
-            0x00100000, 0x00200000, 0x00400000, 0x00800000, 0x01000000, 0x02000000, 0x04000000, 0x08000001,
-            0x10000002, 0x20000005, 0x4000000A, 0x80000014, 0x00000028, 0x00000050, 0x00000020, 0x00000040
-        ];
-        const LFSR88_Z3_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00200000, 0x00400000, 0x00800000, 0x01000000,
-            0x02000001, 0x04000002, 0x08000004, 0x10000009, 0x20000012, 0x40000024, 0x80000048, 0x00000090,
-            0x00000120, 0x00000240, 0x00000480, 0x00000900, 0x00001200, 0x00002400, 0x00004800, 0x00009000,
-            0x00012000, 0x00024000, 0x00048000, 0x00090000, 0x00120000, 0x00040000, 0x00080000, 0x00100000
-        ];
-
         let n_z1 = maths::modulo(n, LFSR88::Z1_CYCLE_LEN);
         self.sanitise_z1();
-        let lfsr88_matrix = BitColumnMatrix32::new(&LFSR88_Z1_MATRIX_ARRAY);
-        let lfsr88_mult = lfsr88_matrix.pow(n_z1);
+        let lfsr88_mult = LFSR88::matrices()[0].clone().pow(n_z1);
         self.z1 = lfsr88_mult.dot_vec(self.z1);
 
         let n_z2 = maths::modulo(n, LFSR88::Z2_CYCLE_LEN);
         self.sanitise_z2();
-        let lfsr88_matrix = BitColumnMatrix32::new(&LFSR88_Z2_MATRIX_ARRAY);
-        let lfsr88_mult = lfsr88_matrix.pow(n_z2);
+        let lfsr88_mult = LFSR88::matrices()[1].clone().pow(n_z2);
         self.z2 = lfsr88_mult.dot_vec(self.z2);
 
         let n_z3 = maths::modulo(n, LFSR88::Z3_CYCLE_LEN);
         self.sanitise_z3();
-        let lfsr88_matrix = BitColumnMatrix32::new(&LFSR88_Z3_MATRIX_ARRAY);
-        let lfsr88_mult = lfsr88_matrix.pow(n_z3);
+        let lfsr88_mult = LFSR88::matrices()[2].clone().pow(n_z3);
         self.z3 = lfsr88_mult.dot_vec(self.z3);
     }
 }
+impl Period for LFSR88 {
+    fn period() -> BigUint {
+        BigUint::from_u64(LFSR88::Z1_CYCLE_LEN as u64)
+            .lcm(&BigUint::from_u64(LFSR88::Z2_CYCLE_LEN as u64))
+            .lcm(&BigUint::from_u64(LFSR88::Z3_CYCLE_LEN as u64))
+    }
+}
 
 
 /* LFSR113 -------------------------------------------------------------------*/
@@ -886,6 +1402,13 @@ impl LFSR113 {
             z4: lfsr_seed_z(seed4),
         }
     }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// See [`SplitMix64`] for details of the seed expansion.
+    pub fn from_u64_seed(seed: u64) -> LFSR113 {
+        let mut sm = SplitMix64::new(seed);
+        LFSR113::new(sm.next_u32(), sm.next_u32(), sm.next_u32(), sm.next_u32())
+    }
     fn sanitise_z1(&mut self) {
         self.z1 = lfsr_sanitise_z(self.z1, LFSR113::Z1_MIN);
     }
@@ -913,6 +1436,49 @@ impl LFSR113 {
     fn current(&self) -> u32 {
         self.z1 ^ self.z2 ^ self.z3 ^ self.z4
     }
+    /// Transition matrices of the components' steps, for jumpahead.
+    fn matrices() -> &'static [BitColumnMatrix32; 4] {
+        static MATRICES: OnceLock<[BitColumnMatrix32; 4]> = OnceLock::new();
+        MATRICES.get_or_init(|| {
+            // Each component steps independently of the others.
+            let step = |z: u32| {
+                let mut s = LFSR113 { z1: z, z2: z, z3: z, z4: z };
+                s.next_z1();
+                s.next_z2();
+                s.next_z3();
+                s.next_z4();
+                s
+            };
+            [
+                BitColumnMatrix32::from_linear_fn(|z| step(z).z1),
+                BitColumnMatrix32::from_linear_fn(|z| step(z).z2),
+                BitColumnMatrix32::from_linear_fn(|z| step(z).z3),
+                BitColumnMatrix32::from_linear_fn(|z| step(z).z4),
+            ]
+        })
+    }
+}
+impl LinearStep for LFSR113 {
+    fn zero_state() -> LFSR113 {
+        LFSR113 {
+            z1: 0,
+            z2: 0,
+            z3: 0,
+            z4: 0,
+        }
+    }
+    fn step(&mut self) {
+        self.next_z1();
+        self.next_z2();
+        self.next_z3();
+        self.next_z4();
+    }
+    fn add_state(&mut self, other: &LFSR113) {
+        self.z1 ^= other.z1;
+        self.z2 ^= other.z2;
+        self.z3 ^= other.z3;
+        self.z4 ^= other.z4;
+    }
 }
 impl RngCore for LFSR113 {
     fn next_u32(&mut self) -> u32 {
@@ -934,60 +1500,2384 @@ impl RngCore for LFSR113 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for LFSR113 {
     fn jumpahead<N>(&mut self, n: N)
         where N: maths::IntTypes
     {
-        const LFSR113_Z1_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00080000, 0x00100000, 0x00200000, 0x00400000, 0x00800000, 0x01000000, 0x02000001,
-            0x04000002, 0x08000004, 0x10000008, 0x20000010, 0x40000020, 0x80000041, 0x00000082, 0x00000104,
-            0x00000208, 0x00000410, 0x00000820, 0x00001040, 0x00002080, 0x00004100, 0x00008200, 0x00010400,
-            0x00020800, 0x00041000, 0x00002000, 0x00004000, 0x00008000, 0x00010000, 0x00020000, 0x00040000
-        ];
-        const LFSR113_Z2_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00000000, 0x00000000, 0x00000020, 0x00000040, 0x00000080, 0x00000100, 0x00000200,
-            0x00000400, 0x00000800, 0x00001000, 0x00002000, 0x00004000, 0x00008000, 0x00010000, 0x00020000,
-            0x00040000, 0x00080000, 0x00100000, 0x00200000, 0x00400000, 0x00800000, 0x01000000, 0x02000000,
-            0x04000000, 0x08000001, 0x10000002, 0x20000005, 0x4000000A, 0x80000014, 0x00000008, 0x00000010
-        ];
-        const LFSR113_Z3_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000800, 0x00001000, 0x00002000, 0x00004000,
-            0x00008001, 0x00010002, 0x00020004, 0x00040008, 0x00080010, 0x00100020, 0x00200040, 0x00400080,
-            0x00800100, 0x01000200, 0x02000400, 0x04000000, 0x08000000, 0x10000001, 0x20000002, 0x40000004,
-            0x80000008, 0x00000010, 0x00000020, 0x00000040, 0x00000080, 0x00000100, 0x00000200, 0x00000400
-        ];
-        const LFSR113_Z4_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00100000,
-            0x00200000, 0x00400001, 0x00800002, 0x01000004, 0x02000009, 0x04000012, 0x08000024, 0x10000048,
-            0x20000090, 0x40000120, 0x80000240, 0x00000480, 0x00000900, 0x00001200, 0x00002400, 0x00004800,
-            0x00009000, 0x00012000, 0x00024000, 0x00048000, 0x00090000, 0x00020000, 0x00040000, 0x00080000
-        ];
-
         let n_z1 = maths::modulo(n, LFSR113::Z1_CYCLE_LEN);
         self.sanitise_z1();
-        let lfsr113_matrix = BitColumnMatrix32::new(&LFSR113_Z1_MATRIX_ARRAY);
-        let lfsr113_mult = lfsr113_matrix.pow(n_z1);
+        let lfsr113_mult = LFSR113::matrices()[0].clone().pow(n_z1);
         self.z1 = lfsr113_mult.dot_vec(self.z1);
 
         let n_z2 = maths::modulo(n, LFSR113::Z2_CYCLE_LEN);
         self.sanitise_z2();
-        let lfsr113_matrix = BitColumnMatrix32::new(&LFSR113_Z2_MATRIX_ARRAY);
-        let lfsr113_mult = lfsr113_matrix.pow(n_z2);
+        let lfsr113_mult = LFSR113::matrices()[1].clone().pow(n_z2);
         self.z2 = lfsr113_mult.dot_vec(self.z2);
 
         let n_z3 = maths::modulo(n, LFSR113::Z3_CYCLE_LEN);
         self.sanitise_z3();
-        let lfsr113_matrix = BitColumnMatrix32::new(&LFSR113_Z3_MATRIX_ARRAY);
-        let lfsr113_mult = lfsr113_matrix.pow(n_z3);
+        let lfsr113_mult = LFSR113::matrices()[2].clone().pow(n_z3);
         self.z3 = lfsr113_mult.dot_vec(self.z3);
 
         let n_z4 = maths::modulo(n, LFSR113::Z4_CYCLE_LEN);
         self.sanitise_z4();
-        let lfsr113_matrix = BitColumnMatrix32::new(&LFSR113_Z4_MATRIX_ARRAY);
-        let lfsr113_mult = lfsr113_matrix.pow(n_z4);
+        let lfsr113_mult = LFSR113::matrices()[3].clone().pow(n_z4);
         self.z4 = lfsr113_mult.dot_vec(self.z4);
     }
 }
+impl Period for LFSR113 {
+    fn period() -> BigUint {
+        BigUint::from_u64(LFSR113::Z1_CYCLE_LEN as u64)
+            .lcm(&BigUint::from_u64(LFSR113::Z2_CYCLE_LEN as u64))
+            .lcm(&BigUint::from_u64(LFSR113::Z3_CYCLE_LEN as u64))
+            .lcm(&BigUint::from_u64(LFSR113::Z4_CYCLE_LEN as u64))
+    }
+}
+
+
+/* TauswortheComponent -------------------------------------------------------*/
+
+/// Distinct prime factors of 2^k-1, for k from 1 to 64.
+///
+/// These are needed to check that a characteristic polynomial of degree k is primitive.
+pub(crate) const MERSENNE_FACTORS: [&[u128]; 64] = [
+    &[],
+    &[3],
+    &[7],
+    &[3, 5],
+    &[31],
+    &[3, 7],
+    &[127],
+    &[3, 5, 17],
+    &[7, 73],
+    &[3, 11, 31],
+    &[23, 89],
+    &[3, 5, 7, 13],
+    &[8191],
+    &[3, 43, 127],
+    &[7, 31, 151],
+    &[3, 5, 17, 257],
+    &[131071],
+    &[3, 7, 19, 73],
+    &[524287],
+    &[3, 5, 11, 31, 41],
+    &[7, 127, 337],
+    &[3, 23, 89, 683],
+    &[47, 178481],
+    &[3, 5, 7, 13, 17, 241],
+    &[31, 601, 1801],
+    &[3, 2731, 8191],
+    &[7, 73, 262657],
+    &[3, 5, 29, 43, 113, 127],
+    &[233, 1103, 2089],
+    &[3, 7, 11, 31, 151, 331],
+    &[2147483647],
+    &[3, 5, 17, 257, 65537],
+    &[7, 23, 89, 599479],
+    &[3, 43691, 131071],
+    &[31, 71, 127, 122921],
+    &[3, 5, 7, 13, 19, 37, 73, 109],
+    &[223, 616318177],
+    &[3, 174763, 524287],
+    &[7, 79, 8191, 121369],
+    &[3, 5, 11, 17, 31, 41, 61681],
+    &[13367, 164511353],
+    &[3, 7, 43, 127, 337, 5419],
+    &[431, 9719, 2099863],
+    &[3, 5, 23, 89, 397, 683, 2113],
+    &[7, 31, 73, 151, 631, 23311],
+    &[3, 47, 178481, 2796203],
+    &[2351, 4513, 13264529],
+    &[3, 5, 7, 13, 17, 97, 241, 257, 673],
+    &[127, 4432676798593],
+    &[3, 11, 31, 251, 601, 1801, 4051],
+    &[7, 103, 2143, 11119, 131071],
+    &[3, 5, 53, 157, 1613, 2731, 8191],
+    &[6361, 69431, 20394401],
+    &[3, 7, 19, 73, 87211, 262657],
+    &[23, 31, 89, 881, 3191, 201961],
+    &[3, 5, 17, 29, 43, 113, 127, 15790321],
+    &[7, 32377, 524287, 1212847],
+    &[3, 59, 233, 1103, 2089, 3033169],
+    &[179951, 3203431780337],
+    &[3, 5, 7, 11, 13, 31, 41, 61, 151, 331, 1321],
+    &[2305843009213693951],
+    &[3, 715827883, 2147483647],
+    &[7, 73, 127, 337, 92737, 649657],
+    &[3, 5, 17, 257, 641, 65537, 6700417],
+];
+
+/// Reason that `TauswortheComponent` parameters were rejected
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TauswortheError {
+    /// The parameters don't satisfy 0 < 2q < k <= word size, and 0 < s <= k-q.
+    InvalidParameters,
+    /// The characteristic polynomial isn't primitive, so the period isn't 2^k-1.
+    NotPrimitive,
+}
+
+impl std::fmt::Display for TauswortheError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TauswortheError::InvalidParameters => write!(f, "invalid Tausworthe parameters (k, q, s)"),
+            TauswortheError::NotPrimitive => write!(f, "Tausworthe characteristic polynomial is not primitive"),
+        }
+    }
+}
+
+impl std::error::Error for TauswortheError {}
+
+/// TauswortheComponent -- one LFSR (Tausworthe) component of a combined LFSR generator
+///
+/// This is the component generator that L'Ecuyer's combined LFSR generators are built from, such
+/// as [`LFSR88`] and [`LFSR113`]. It is parameterised by (k, q, s) as in L'Ecuyer's tables
+/// [[lecuyer1]](#lecuyer1) [[lecuyer2]](#lecuyer2), and by the word size. It is based on the
+/// trinomial x^k + x^q + 1, and only the most significant k bits of the state word are part of
+/// the recurrence.
+///
+/// At construction, the one-step transition matrix is derived from the recurrence as a
+/// `BitColumnMatrix`, and its characteristic polynomial is checked to be primitive. So a
+/// successfully constructed component has the maximal period 2^k-1. The same matrix is used for
+/// jumpahead.
+///
+/// The `LFSR88` generator can be assembled from three components, seeded the same way as
+/// `LFSR88::new()`:
+///
+///     use rand_core::RngCore;
+///     use simplerandom::{RngJumpAhead, TauswortheComponent32};
+///     let seed = |s: u32| s ^ (s << 16);
+///     let mut z1 = TauswortheComponent32::new(31, 13, 12, seed(1)).unwrap();
+///     let mut z2 = TauswortheComponent32::new(29, 2, 4, seed(2)).unwrap();
+///     let mut z3 = TauswortheComponent32::new(28, 3, 17, seed(3)).unwrap();
+///     let mut s = simplerandom::LFSR88::new(1, 2, 3);
+///     for _ in 0..10 {
+///         assert_eq!(z1.next_z() ^ z2.next_z() ^ z3.next_z(), s.next_u32());
+///     }
+///     z1.jumpahead(1_000_000_i64);
+///     z2.jumpahead(1_000_000_i64);
+///     z3.jumpahead(1_000_000_i64);
+///     s.jumpahead(1_000_000_i64);
+///     assert_eq!(z1.next_z() ^ z2.next_z() ^ z3.next_z(), s.next_u32());
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TauswortheComponent<T, const WIDTH: usize>
+    where T: bitcolumnmatrix::BitColumnMatrixInt
+{
+    k: u32,
+    q: u32,
+    s: u32,
+    z: T,
+    matrix: bitcolumnmatrix::BitColumnMatrix<T, WIDTH>,
+}
+
+/// Tausworthe component with a 32-bit word, as used by `LFSR88` and `LFSR113`.
+pub type TauswortheComponent32 = TauswortheComponent<u32, 32>;
+/// Tausworthe component with a 64-bit word, as used by L'Ecuyer's LFSR258.
+pub type TauswortheComponent64 = TauswortheComponent<u64, 64>;
+
+impl<T, const WIDTH: usize> TauswortheComponent<T, WIDTH>
+    where T: bitcolumnmatrix::BitColumnMatrixInt
+{
+    /// Create a component with parameters (k, q, s), and initial state `seed`.
+    ///
+    /// An error is returned if the parameters are invalid, or if they don't give a maximal
+    /// period.
+    ///
+    /// # Panics
+    ///
+    /// If `WIDTH` isn't the bit width of `T`.
+    pub fn new(k: u32, q: u32, s: u32, seed: T) -> Result<TauswortheComponent<T, WIDTH>, TauswortheError> {
+        assert_eq!(WIDTH, T::zero().count_zeros() as usize, "TauswortheComponent WIDTH doesn't match the word type");
+        if !(0 < q && 2 * q < k && k as usize <= WIDTH && 0 < s && s <= k - q) {
+            return Err(TauswortheError::InvalidParameters);
+        }
+        let matrix = bitcolumnmatrix::BitColumnMatrix::from_linear_fn(|z| tausworthe_next_z(z, k, q, s));
+
+        // Find the characteristic polynomial of the recurrence, from the sequence of the most
+        // significant bit, by Berlekamp-Massey. Start from the all-ones state, which has non-zero
+        // recurrence bits.
+        let mut z = !T::ZERO;
+        let mut bits = Vec::with_capacity(2 * k as usize);
+        for _ in 0..2 * k {
+            z = matrix.dot_vec(z);
+            bits.push(z >> (WIDTH - 1) != T::ZERO);
+        }
+        let char_poly = gf2poly::Gf2Poly::minimal_polynomial(&bits);
+        if char_poly.degree() != Some(k as usize) || !char_poly.is_primitive(MERSENNE_FACTORS[k as usize - 1]) {
+            return Err(TauswortheError::NotPrimitive);
+        }
+
+        Ok(TauswortheComponent { k, q, s, z: seed, matrix })
+    }
+    /// The parameters (k, q, s).
+    pub fn parameters(&self) -> (u32, u32, u32) {
+        (self.k, self.q, self.s)
+    }
+    /// The one-step transition matrix.
+    pub fn matrix(&self) -> &bitcolumnmatrix::BitColumnMatrix<T, WIDTH> {
+        &self.matrix
+    }
+    /// The period, 2^k-1.
+    pub fn cycle_len(&self) -> u128 {
+        (1_u128 << self.k) - 1
+    }
+    /// The current state.
+    pub fn state(&self) -> T {
+        self.z
+    }
+    /// Minimum state value, for which the most significant k bits are not all zero.
+    fn min_value(&self) -> T {
+        T::ONE << (WIDTH - self.k as usize)
+    }
+    fn sanitise(&mut self) {
+        if self.z < self.min_value() {
+            self.z = !self.z;
+        }
+    }
+    /// Advance the state by one step, and return the new state.
+    pub fn next_z(&mut self) -> T {
+        self.sanitise();
+        self.z = tausworthe_next_z(self.z, self.k, self.q, self.s);
+        self.z
+    }
+}
+impl<T, const WIDTH: usize> RngJumpAhead for TauswortheComponent<T, WIDTH>
+    where T: bitcolumnmatrix::BitColumnMatrixInt
+{
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        let n_z = maths::modulo(n, self.cycle_len());
+        self.sanitise();
+        let mult = self.matrix.clone().pow(n_z);
+        self.z = mult.dot_vec(self.z);
+    }
+}
+
+/// One step of the Tausworthe recurrence with parameters (k, q, s).
+///
+/// This is the general form of `lfsr_next_z()`.
+fn tausworthe_next_z<T>(z: T, k: u32, q: u32, s: u32) -> T
+    where T: bitcolumnmatrix::BitColumnMatrixInt
+{
+    let width = T::zero().count_zeros();
+    let mask = !T::ZERO << (width - k) as usize;
+    let b = ((z << q as usize) ^ z) >> (k - s) as usize;
+    ((z & mask) << s as usize) ^ b
+}
+
+
+/* PCG32 ---------------------------------------------------------------------*/
+
+/// PCG32 -- Permuted congruential random number generator by O'Neill
+///
+/// This is the PCG XSH-RR variant, with a 64-bit state and a 32-bit output. The state advances by
+/// a 64-bit congruential generator, x[n]=6364136223846793005x[n-1]+c, and the output is a
+/// permutation of the previous state: an xorshift of the high bits, followed by a random rotation.
+/// It has period 2^64.
+///
+/// The increment c selects one of 2^63 independent streams. It must be odd, so the stream
+/// number is shifted left by 1 and the lowest bit set. Seeding follows the reference
+/// `pcg32_srandom_r()`, so output matches the PCG C implementation for the same seed and stream.
+///
+/// Since the state is a congruential generator, jumpahead is done the same way as for `Cong`,
+/// with time order `O(log n)`.
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::PCG32::with_stream(42, 54);
+///     let r = s.next_u32();
+///     assert_eq!(r, 0xa15c02b7);
+///     let r = s.next_u32();
+///     assert_eq!(r, 0x7b47f409);
+///     let r = s.next_u32();
+///     assert_eq!(r, 0xba1d3330);
+///     let r = s.next_u32();
+///     assert_eq!(r, 0x83d2f293);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1006625592);
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PCG32 {
+    state: u64,
+    inc: u64,
+}
+
+impl PCG32 {
+    const M: u64 = 6364136223846793005;
+    const DEFAULT_INC: u64 = 1442695040888963407;
+    const CYCLE_LEN: u128 = 1 << 64;
+
+    /// Seed the generator, using the default stream of the PCG reference implementation.
+    pub fn new(seed1: u64) -> PCG32 {
+        PCG32::with_stream(seed1, PCG32::DEFAULT_INC >> 1)
+    }
+    /// Seed the generator, and select one of 2^63 streams.
+    ///
+    /// The top bit of `stream` is ignored.
+    pub fn with_stream(seed1: u64, stream: u64) -> PCG32 {
+        let mut pcg = PCG32 {
+            state: 0,
+            inc: (stream << 1) | 1,
+        };
+        pcg.next_state();
+        pcg.state = pcg.state.wrapping_add(seed1);
+        pcg.next_state();
+        pcg
+    }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// See [`SplitMix64`] for details of the seed expansion.
+    pub fn from_u64_seed(seed: u64) -> PCG32 {
+        let mut sm = SplitMix64::new(seed);
+        PCG32::with_stream(sm.next_u64(), sm.next_u64())
+    }
+    fn next_state(&mut self) {
+        self.state = self.state.wrapping_mul(PCG32::M).wrapping_add(self.inc);
+    }
+    fn output(state: u64) -> u32 {
+        let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+        let rot = (state >> 59) as u32;
+        xorshifted.rotate_right(rot)
+    }
+}
+impl RngCore for PCG32 {
+    fn next_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.next_state();
+
+        PCG32::output(old_state)
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl RngJumpAhead for PCG32 {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        let n_mod = maths::modulo(n, PCG32::CYCLE_LEN);
+        let mult_exp = maths::wrapping_pow(PCG32::M, n_mod);
+        let add_const = maths::wrapping_geom_series(PCG32::M, n_mod).wrapping_mul(self.inc);
+        self.state = mult_exp.wrapping_mul(self.state).wrapping_add(add_const);
+    }
+}
+impl Period for PCG32 {
+    fn period() -> BigUint {
+        BigUint::from_u128(PCG32::CYCLE_LEN)
+    }
+}
+
+
+/* MRG32k3a ------------------------------------------------------------------*/
+
+/// The default seed of L'Ecuyer's RngStreams package, for all 6 seed values of `MRG32k3a`. A
+/// component whose 3 seed values are all zero is also seeded with this.
+pub const MRG32K3A_DEFAULT_SEED: u32 = 12345;
+
+fn mrg_sanitise(s: [u32; 3], m: u32) -> [u32; 3] {
+    let mut result = s;
+    for x in result.iter_mut() {
+        if *x >= m {
+            *x -= m;
+        }
+    }
+    // An all-zero state is a fixed point of the recurrence, so the component would output only
+    // zeros.
+    if result == [0, 0, 0] {
+        result = [MRG32K3A_DEFAULT_SEED; 3];
+    }
+    result
+}
+
+/// MRG32k3a -- Combined multiple recursive random number generator by L'Ecuyer
+///
+/// It combines 2 multiple recursive generators (MRG) of order 3:
+///
+/// x1[n]=(1403580x1[n-2]-810728x1[n-3]) mod 4294967087,
+/// x2[n]=(527612x2[n-1]-1370589x2[n-3]) mod 4294944443,
+///
+/// and outputs (x1[n]-x2[n]) mod 4294967087, in the range [1..4294967087]. It is the generator
+/// used by L'Ecuyer's RngStreams package. The period is approximately 2^191.
+///
+/// Each component has 3 seed values, which are reduced modulo the component's modulus. If all 3
+/// seed values of a component are zero, they are replaced by L'Ecuyer's default seed
+/// [`MRG32K3A_DEFAULT_SEED`], which is 12345 for all 6 seed values.
+///
+/// Jumpahead is done by raising each component's 3×3 transition matrix to the power `n`, modulo
+/// its modulus, using `ModMatrix`.
+///
+/// "Good Parameters and Implementations for Combined Multiple Recursive Random Number Generators"
+/// P. L'Ecuyer
+/// Operations Research, 47, 1 (1999), 159–164.
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::MRG32k3a::new(12345, 12345, 12345, 12345, 12345, 12345);
+///     let r = s.next_u32();
+///     assert_eq!(r, 545508589);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1368065410);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1327943761);
+///     let r = s.next_f64();
+///     assert_eq!(r, 0.8258468629271136);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u32();
+///     assert_eq!(r, 3202186081);
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MRG32k3a {
+    s1: [u32; 3],
+    s2: [u32; 3],
+}
+
+impl MRG32k3a {
+    const M1: u32 = 4294967087;
+    const M2: u32 = 4294944443;
+    const A12: u32 = 1403580;
+    const A13N: u32 = 810728;
+    const A21: u32 = 527612;
+    const A23N: u32 = 1370589;
+    const NORM: f64 = 1.0 / (MRG32k3a::M1 as f64 + 1.0);
+    const S1_CYCLE_LEN: u128 = (MRG32k3a::M1 as u128).pow(3) - 1;
+    const S2_CYCLE_LEN: u128 = (MRG32k3a::M2 as u128).pow(3) - 1;
+
+    pub fn new(seed1: u32, seed2: u32, seed3: u32, seed4: u32, seed5: u32, seed6: u32) -> MRG32k3a {
+        MRG32k3a {
+            s1: [seed1, seed2, seed3],
+            s2: [seed4, seed5, seed6],
+        }
+    }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// See [`SplitMix64`] for details of the seed expansion.
+    pub fn from_u64_seed(seed: u64) -> MRG32k3a {
+        let mut sm = SplitMix64::new(seed);
+        MRG32k3a::new(sm.next_u32(), sm.next_u32(), sm.next_u32(), sm.next_u32(), sm.next_u32(), sm.next_u32())
+    }
+    fn sanitise(&mut self) {
+        self.s1 = mrg_sanitise(self.s1, MRG32k3a::M1);
+        self.s2 = mrg_sanitise(self.s2, MRG32k3a::M2);
+    }
+    fn next_s1(&mut self) {
+        let m1 = MRG32k3a::M1;
+        let p1 = maths::add_mod(maths::mul_mod(MRG32k3a::A12, self.s1[1], m1),
+                                m1 - maths::mul_mod(MRG32k3a::A13N, self.s1[0], m1),
+                                m1);
+        self.s1 = [self.s1[1], self.s1[2], p1];
+    }
+    fn next_s2(&mut self) {
+        let m2 = MRG32k3a::M2;
+        let p2 = maths::add_mod(maths::mul_mod(MRG32k3a::A21, self.s2[2], m2),
+                                m2 - maths::mul_mod(MRG32k3a::A23N, self.s2[0], m2),
+                                m2);
+        self.s2 = [self.s2[1], self.s2[2], p2];
+    }
+    fn current(&self) -> u32 {
+        let p1 = self.s1[2];
+        let p2 = self.s2[2];
+        if p1 > p2 {
+            p1 - p2
+        } else {
+            p1.wrapping_sub(p2).wrapping_add(MRG32k3a::M1)
+        }
+    }
+    /// Generate a double value in the range (0, 1), the same as L'Ecuyer's implementation.
+    pub fn next_f64(&mut self) -> f64 {
+        self.next_u32() as f64 * MRG32k3a::NORM
+    }
+}
+impl RngCore for MRG32k3a {
+    fn next_u32(&mut self) -> u32 {
+        self.sanitise();
+        self.next_s1();
+        self.next_s2();
+
+        self.current()
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl RngJumpAhead for MRG32k3a {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        const M1: u32 = MRG32k3a::M1;
+        const M2: u32 = MRG32k3a::M2;
+        const MRG32K3A_S1_MATRIX_ARRAY: [[u32; 3]; 3] = [
+            [0, 1, 0],
+            [0, 0, 1],
+            [M1 - MRG32k3a::A13N, MRG32k3a::A12, 0],
+        ];
+        const MRG32K3A_S2_MATRIX_ARRAY: [[u32; 3]; 3] = [
+            [0, 1, 0],
+            [0, 0, 1],
+            [M2 - MRG32k3a::A23N, 0, MRG32k3a::A21],
+        ];
+
+        self.sanitise();
+
+        let n_s1 = maths::modulo(n, MRG32k3a::S1_CYCLE_LEN);
+        let mrg_matrix = ModMatrix3::new(&MRG32K3A_S1_MATRIX_ARRAY, M1);
+        let mrg_mult = mrg_matrix.pow(n_s1);
+        self.s1 = mrg_mult.dot_vec(&self.s1);
+
+        let n_s2 = maths::modulo(n, MRG32k3a::S2_CYCLE_LEN);
+        let mrg_matrix = ModMatrix3::new(&MRG32K3A_S2_MATRIX_ARRAY, M2);
+        let mrg_mult = mrg_matrix.pow(n_s2);
+        self.s2 = mrg_mult.dot_vec(&self.s2);
+    }
+}
+impl Period for MRG32k3a {
+    fn period() -> BigUint {
+        BigUint::from_u128(MRG32k3a::S1_CYCLE_LEN).lcm(&BigUint::from_u128(MRG32k3a::S2_CYCLE_LEN))
+    }
+}
+
+
+/* Linear generator jumpahead by characteristic polynomial -------------------*/
+
+/// A generator whose state transition is linear over Galois(2).
+///
+/// This is what is needed for jumpahead by the characteristic polynomial method, with
+/// [`linear_jumpahead`]. That method is needed for generators whose state is too large to use
+/// `BitColumnMatrix` efficiently. The step must be the pure linear transition, without any
+/// sanitising of an all-zero state.
+pub trait LinearStep: Clone {
+    /// A state of all zeros.
+    fn zero_state() -> Self;
+    /// Advance the state by one step.
+    fn step(&mut self);
+    /// Add (xor) another state into this one.
+    fn add_state(&mut self, other: &Self);
+}
+
+/// Jumpahead by the characteristic polynomial method, from [[haramoto1]](#haramoto1).
+///
+/// To jump ahead by `n`, x^n mod p(x) is calculated, where p(x) is the characteristic polynomial
+/// of the generator's transition matrix T. That gives a polynomial g(x) of lower degree than
+/// p(x), and since p(T) = 0, T^n = g(T). Then g(T) is applied to the state by Horner's method,
+/// using only the generator's `step()`.
+///
+/// Some generators' states have "dead" bits that are not part of the linear recurrence (e.g. 31
+/// bits of the Mersenne Twister state), which the characteristic polynomial doesn't describe.
+/// Those bits are discarded after one step, so the generator is first advanced by one step
+/// before applying g(T).
+///
+/// The state is not sanitised, since `LinearStep` is only the pure linear transition. So the
+/// caller must pass a sanitised state. Eg an all-zero `SHR3` state, which the generator replaces
+/// before generating, stays all-zero here. A generator's `RngJumpAhead::jumpahead()` that uses
+/// this must sanitise its state first, if the generator has states that it replaces.
+///
+/// For a generator with a large state, p(x) can be found from a bit of its output by
+/// `Gf2Poly::minimal_polynomial()`. For a state of up to 128 bits, it is given by
+/// `BitColumnMatrix::char_poly()` of the transition matrix. Eg for `SHR3`:
+///
+///     use rand_core::RngCore;
+///     use simplerandom::bitcolumnmatrix::BitColumnMatrix;
+///     use simplerandom::{linear_jumpahead, RngJumpAhead, SHR3};
+///     let matrix = BitColumnMatrix::<u32, 32>::from_linear_fn(|z| SHR3::new(z).next_u32());
+///     let char_poly = matrix.char_poly();
+///     let mut s = SHR3::new(1);
+///     let t = linear_jumpahead(&s, 1_000_000_000_u32, &char_poly);
+///     s.jumpahead(1_000_000_000_u32);
+///     assert_eq!(s, t);
+///
+/// # Panics
+///
+/// If `n` is negative and p(x) has no constant term, since then x has no inverse modulo p(x).
+pub fn linear_jumpahead<S, N>(state: &S, n: N, char_poly: &gf2poly::Gf2Poly) -> S
+    where S: LinearStep,
+          N: maths::IntTypes
+{
+    let n_abs: u128 = NumCast::from(maths::abs_as_unsigned(n)).unwrap_or(0);
+    let mut state_work = state.clone();
+    if n == N::ZERO {
+        return state_work;
+    }
+    state_work.step();
+    let g = if n > N::ZERO {
+        gf2poly::Gf2Poly::x().pow_mod(n_abs - 1, char_poly)
+    } else {
+        // Jump back by n+1, since one step has been done already. x^-(n+1) is the inverse of
+        // x^(n+1), which exists if p(x) has a constant term of 1.
+        gf2poly::Gf2Poly::x().pow_mod(n_abs + 1, char_poly).inverse_mod(char_poly)
+            .expect("backwards jump needs a characteristic polynomial with a constant term")
+    };
+
+    let mut result = S::zero_state();
+    let mut g_degree = g.degree().map_or(0, |d| d + 1);
+    let mut first = true;
+    while g_degree > 0 {
+        g_degree -= 1;
+        if !first {
+            result.step();
+        }
+        if g.coefficient(g_degree) {
+            result.add_state(&state_work);
+            first = false;
+        }
+    }
+    result
+}
+
+
+/* MT19937 -------------------------------------------------------------------*/
+
+/// MT19937 -- Mersenne Twister random number generator by Matsumoto and Nishimura
+///
+/// This is the 32-bit Mersenne Twister, with a state of 624 32-bit words. It has period
+/// 2^19937-1. The seeding is the same as the reference `init_genrand()`, so the output matches
+/// the C++ `std::mt19937` for the same seed. The C++ default seed is 5489.
+///
+/// The state is kept as a circular buffer, and one word of the state is updated per output, rather
+/// than updating all words at once as the reference implementation does. The outputs are
+/// identical, but each step is the same linear transition, as needed for jumpahead.
+///
+/// The state is too large for matrix jumpahead, so jumpahead is done by the characteristic
+/// polynomial method [[haramoto1]](#haramoto1). The characteristic polynomial is calculated once,
+/// by the Berlekamp–Massey algorithm on the generator's output. Jumpahead takes time order
+/// `O(log n)`, but each step is an operation on polynomials of degree 19937, so it is much slower
+/// than jumpahead of the small-state generators.
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::MT19937::new(5489);
+///     let r = s.next_u32();
+///     assert_eq!(r, 3499211612);
+///     let r = s.next_u32();
+///     assert_eq!(r, 581869302);
+///     let r = s.next_u32();
+///     assert_eq!(r, 3890346734);
+///     let r = s.next_u32();
+///     assert_eq!(r, 3586334585);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1470212236);
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MT19937 {
+    mt: [u32; MT19937::N],
+    index: usize,
+}
+
+impl MT19937 {
+    const N: usize = 624;
+    const M: usize = 397;
+    const MATRIX_A: u32 = 0x9908B0DF;
+    const UPPER_MASK: u32 = 0x80000000;
+    const LOWER_MASK: u32 = 0x7FFFFFFF;
+    const CHAR_POLY_DEGREE: usize = 19937;
+
+    pub fn new(seed1: u32) -> MT19937 {
+        let mut mt = [0_u32; MT19937::N];
+        mt[0] = seed1;
+        for i in 1..MT19937::N {
+            mt[i] = 1812433253_u32.wrapping_mul(mt[i - 1] ^ (mt[i - 1] >> 30)).wrapping_add(i as u32);
+        }
+        MT19937 {
+            mt,
+            index: 0,
+        }
+    }
+    fn next_mt(&mut self) -> u32 {
+        let i = self.index;
+        let y = (self.mt[i] & MT19937::UPPER_MASK) | (self.mt[(i + 1) % MT19937::N] & MT19937::LOWER_MASK);
+        let mut value = self.mt[(i + MT19937::M) % MT19937::N] ^ (y >> 1);
+        if y & 1 != 0 {
+            value ^= MT19937::MATRIX_A;
+        }
+        self.mt[i] = value;
+        self.index = (i + 1) % MT19937::N;
+        value
+    }
+    fn temper(y: u32) -> u32 {
+        let mut y = y;
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9D2C5680;
+        y ^= (y << 15) & 0xEFC60000;
+        y ^= y >> 18;
+        y
+    }
+    fn char_poly() -> &'static gf2poly::Gf2Poly {
+        static CHAR_POLY: OnceLock<gf2poly::Gf2Poly> = OnceLock::new();
+        CHAR_POLY.get_or_init(|| {
+            let mut s = MT19937::new(5489);
+            let bits: Vec<bool> = (0..2 * MT19937::CHAR_POLY_DEGREE).map(|_| s.next_u32() & 1 != 0).collect();
+            gf2poly::Gf2Poly::minimal_polynomial(&bits)
+        })
+    }
+}
+impl LinearStep for MT19937 {
+    fn zero_state() -> MT19937 {
+        MT19937 {
+            mt: [0; MT19937::N],
+            index: 0,
+        }
+    }
+    fn step(&mut self) {
+        self.next_mt();
+    }
+    fn add_state(&mut self, other: &MT19937) {
+        for k in 0..MT19937::N {
+            self.mt[(self.index + k) % MT19937::N] ^= other.mt[(other.index + k) % MT19937::N];
+        }
+    }
+}
+impl RngCore for MT19937 {
+    fn next_u32(&mut self) -> u32 {
+        MT19937::temper(self.next_mt())
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl RngJumpAhead for MT19937 {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        let result = linear_jumpahead(self, n, MT19937::char_poly());
+        let index = (self.index + maths::modulo(n, MT19937::N)) % MT19937::N;
+        for k in 0..MT19937::N {
+            self.mt[(index + k) % MT19937::N] = result.mt[(result.index + k) % MT19937::N];
+        }
+        self.index = index;
+    }
+}
+impl Period for MT19937 {
+    fn period() -> BigUint {
+        &BigUint::from_u64(2).pow(19937) - &BigUint::from_u64(1)
+    }
+}
+
+
+/* MT19937_64 ----------------------------------------------------------------*/
+
+/// MT19937_64 -- 64-bit Mersenne Twister random number generator by Matsumoto and Nishimura
+///
+/// This is the 64-bit Mersenne Twister, with a state of 312 64-bit words. It has period
+/// 2^19937-1. The seeding is the same as the reference `init_genrand64()`, so the output matches
+/// the C++ `std::mt19937_64` for the same seed. The C++ default seed is 5489.
+///
+/// `next_u64()` gives the native 64-bit output. `next_u32()` gives the lower 32 bits of it.
+///
+/// Jumpahead is done by the characteristic polynomial method, the same as for `MT19937`.
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::MT19937_64::new(5489);
+///     let r = s.next_u64();
+///     assert_eq!(r, 14514284786278117030);
+///     let r = s.next_u64();
+///     assert_eq!(r, 4620546740167642908);
+///     let r = s.next_u64();
+///     assert_eq!(r, 13109570281517897720);
+///     let r = s.next_u64();
+///     assert_eq!(r, 17462938647148434322);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u64();
+///     assert_eq!(r, 6072991961006929148);
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MT19937_64 {
+    mt: [u64; MT19937_64::N],
+    index: usize,
+}
+
+impl MT19937_64 {
+    const N: usize = 312;
+    const M: usize = 156;
+    const MATRIX_A: u64 = 0xB5026F5AA96619E9;
+    const UPPER_MASK: u64 = 0xFFFFFFFF80000000;
+    const LOWER_MASK: u64 = 0x7FFFFFFF;
+    const CHAR_POLY_DEGREE: usize = 19937;
+
+    pub fn new(seed1: u64) -> MT19937_64 {
+        let mut mt = [0_u64; MT19937_64::N];
+        mt[0] = seed1;
+        for i in 1..MT19937_64::N {
+            mt[i] = 6364136223846793005_u64.wrapping_mul(mt[i - 1] ^ (mt[i - 1] >> 62)).wrapping_add(i as u64);
+        }
+        MT19937_64 {
+            mt,
+            index: 0,
+        }
+    }
+    fn next_mt(&mut self) -> u64 {
+        let i = self.index;
+        let y = (self.mt[i] & MT19937_64::UPPER_MASK) | (self.mt[(i + 1) % MT19937_64::N] & MT19937_64::LOWER_MASK);
+        let mut value = self.mt[(i + MT19937_64::M) % MT19937_64::N] ^ (y >> 1);
+        if y & 1 != 0 {
+            value ^= MT19937_64::MATRIX_A;
+        }
+        self.mt[i] = value;
+        self.index = (i + 1) % MT19937_64::N;
+        value
+    }
+    fn temper(y: u64) -> u64 {
+        let mut y = y;
+        y ^= (y >> 29) & 0x5555555555555555;
+        y ^= (y << 17) & 0x71D67FFFEDA60000;
+        y ^= (y << 37) & 0xFFF7EEE000000000;
+        y ^= y >> 43;
+        y
+    }
+    fn char_poly() -> &'static gf2poly::Gf2Poly {
+        static CHAR_POLY: OnceLock<gf2poly::Gf2Poly> = OnceLock::new();
+        CHAR_POLY.get_or_init(|| {
+            let mut s = MT19937_64::new(5489);
+            let bits: Vec<bool> = (0..2 * MT19937_64::CHAR_POLY_DEGREE).map(|_| s.next_u64() & 1 != 0).collect();
+            gf2poly::Gf2Poly::minimal_polynomial(&bits)
+        })
+    }
+}
+impl LinearStep for MT19937_64 {
+    fn zero_state() -> MT19937_64 {
+        MT19937_64 {
+            mt: [0; MT19937_64::N],
+            index: 0,
+        }
+    }
+    fn step(&mut self) {
+        self.next_mt();
+    }
+    fn add_state(&mut self, other: &MT19937_64) {
+        for k in 0..MT19937_64::N {
+            self.mt[(self.index + k) % MT19937_64::N] ^= other.mt[(other.index + k) % MT19937_64::N];
+        }
+    }
+}
+impl RngCore for MT19937_64 {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+    fn next_u64(&mut self) -> u64 {
+        MT19937_64::temper(self.next_mt())
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl RngJumpAhead for MT19937_64 {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        let result = linear_jumpahead(self, n, MT19937_64::char_poly());
+        let index = (self.index + maths::modulo(n, MT19937_64::N)) % MT19937_64::N;
+        for k in 0..MT19937_64::N {
+            self.mt[(index + k) % MT19937_64::N] = result.mt[(result.index + k) % MT19937_64::N];
+        }
+        self.index = index;
+    }
+}
+impl Period for MT19937_64 {
+    fn period() -> BigUint {
+        &BigUint::from_u64(2).pow(19937) - &BigUint::from_u64(1)
+    }
+}
+
+
+/* WELL ----------------------------------------------------------------------*/
+
+/// Scale factor 2^-32, for double output.
+const WELL_FACT: f64 = 1.0 / 4294967296.0;
+
+fn well_sanitise<const R: usize>(state: &mut [u32; R]) {
+    if state.iter().all(|&x| x == 0) {
+        *state = [0xFFFFFFFF; R];
+    }
+}
+
+fn well_mat0pos(t: u32, v: u32) -> u32 {
+    v ^ (v >> t)
+}
+
+fn well_mat0neg(t: u32, v: u32) -> u32 {
+    v ^ (v << t)
+}
+
+/// Copy the state window of `src` into `dest`, so the window starts at `index`.
+fn well_set_window<const R: usize>(dest: &mut [u32; R], index: usize, src: &[u32; R], src_index: usize) {
+    for k in 0..R {
+        dest[(index + k) % R] = src[(src_index + k) % R];
+    }
+}
+
+
+/* WELL512a ------------------------------------------------------------------*/
+
+/// WELL512a -- "Well Equidistributed Long-period Linear" random number generator
+///
+/// By Panneton, L'Ecuyer and Matsumoto. It has a state of 16 32-bit words, and period 2^512-1.
+/// It is a linear generator over Galois(2), like the LFSR generators, but with better
+/// equidistribution.
+///
+/// The state must not be all zeros. If the seed is all zeros, the state is set to all ones.
+///
+/// The output matches the reference `WELLRNG512a()`, for the same seed. The reference
+/// implementation returns a double value, which is the same as `next_f64()`.
+///
+/// Jumpahead is done by the characteristic polynomial method [[haramoto1]](#haramoto1).
+///
+/// "Improved Long-Period Generators Based on Linear Recurrences Modulo 2"
+/// F. Panneton, P. L'Ecuyer, M. Matsumoto
+/// ACM Transactions on Mathematical Software, 32, 1 (2006), 1–16.
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::WELL512a::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+///     let r = s.next_f64();
+///     assert_eq!(r, 0.6268921182490885);
+///     let r = s.next_u32();
+///     assert_eq!(r, 2447117626);
+///     let r = s.next_u32();
+///     assert_eq!(r, 752362814);
+///     let r = s.next_u32();
+///     assert_eq!(r, 4237304894);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u32();
+///     assert_eq!(r, 2114221995);
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct WELL512a {
+    state: [u32; WELL512a::R],
+    index: usize,
+}
+
+impl WELL512a {
+    const R: usize = 16;
+    const M1: usize = 13;
+    const M2: usize = 9;
+    const CHAR_POLY_DEGREE: usize = 512;
+
+    pub fn new(seeds: &[u32; WELL512a::R]) -> WELL512a {
+        let mut well = WELL512a {
+            state: *seeds,
+            index: 0,
+        };
+        well_sanitise(&mut well.state);
+        well
+    }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// See [`SplitMix64`] for details of the seed expansion.
+    pub fn from_u64_seed(seed: u64) -> WELL512a {
+        let mut sm = SplitMix64::new(seed);
+        WELL512a::new(&core::array::from_fn(|_| sm.next_u32()))
+    }
+    fn next_state(&mut self) {
+        let i = self.index;
+        let s = &mut self.state;
+        let z0 = s[(i + WELL512a::R - 1) % WELL512a::R];
+        let z1 = well_mat0neg(16, s[i]) ^ well_mat0neg(15, s[(i + WELL512a::M1) % WELL512a::R]);
+        let z2 = well_mat0pos(11, s[(i + WELL512a::M2) % WELL512a::R]);
+        let new_v1 = z1 ^ z2;
+        s[i] = new_v1;
+        s[(i + WELL512a::R - 1) % WELL512a::R] = well_mat0neg(2, z0) ^ well_mat0neg(18, z1) ^ (z2 << 28) ^ (new_v1 ^ ((new_v1 << 5) & 0xDA442D24));
+        self.index = (i + WELL512a::R - 1) % WELL512a::R;
+    }
+    fn current(&self) -> u32 {
+        self.state[self.index]
+    }
+    /// Generate a double value in the range [0, 1), the same as the reference implementation.
+    pub fn next_f64(&mut self) -> f64 {
+        self.next_u32() as f64 * WELL_FACT
+    }
+    fn char_poly() -> &'static gf2poly::Gf2Poly {
+        static CHAR_POLY: OnceLock<gf2poly::Gf2Poly> = OnceLock::new();
+        CHAR_POLY.get_or_init(|| {
+            let mut s = WELL512a::new(&[1; WELL512a::R]);
+            let bits: Vec<bool> = (0..2 * WELL512a::CHAR_POLY_DEGREE).map(|_| s.next_u32() & 1 != 0).collect();
+            gf2poly::Gf2Poly::minimal_polynomial(&bits)
+        })
+    }
+}
+impl LinearStep for WELL512a {
+    fn zero_state() -> WELL512a {
+        WELL512a {
+            state: [0; WELL512a::R],
+            index: 0,
+        }
+    }
+    fn step(&mut self) {
+        self.next_state();
+    }
+    fn add_state(&mut self, other: &WELL512a) {
+        for k in 0..WELL512a::R {
+            self.state[(self.index + k) % WELL512a::R] ^= other.state[(other.index + k) % WELL512a::R];
+        }
+    }
+}
+impl RngCore for WELL512a {
+    fn next_u32(&mut self) -> u32 {
+        self.next_state();
+        self.current()
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl RngJumpAhead for WELL512a {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        let result = linear_jumpahead(self, n, WELL512a::char_poly());
+        // The index steps backwards.
+        let n_mod = maths::modulo(n, WELL512a::R);
+        let index = (self.index + WELL512a::R - n_mod) % WELL512a::R;
+        well_set_window(&mut self.state, index, &result.state, result.index);
+        self.index = index;
+    }
+}
+impl Period for WELL512a {
+    fn period() -> BigUint {
+        &BigUint::from_u64(2).pow(512) - &BigUint::from_u64(1)
+    }
+}
+
+
+/* WELL1024a -----------------------------------------------------------------*/
+
+/// WELL1024a -- "Well Equidistributed Long-period Linear" random number generator
+///
+/// By Panneton, L'Ecuyer and Matsumoto. It has a state of 32 32-bit words, and period 2^1024-1.
+///
+/// The state must not be all zeros. If the seed is all zeros, the state is set to all ones.
+///
+/// The output matches the reference `WELLRNG1024a()`, for the same seed. The reference
+/// implementation returns a double value, which is the same as `next_f64()`.
+///
+/// Jumpahead is done by the characteristic polynomial method [[haramoto1]](#haramoto1).
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let seeds: [u32; 32] = core::array::from_fn(|i| i as u32 + 1);
+///     let mut s = simplerandom::WELL1024a::new(&seeds);
+///     let r = s.next_f64();
+///     assert_eq!(r, 0.3468248078133911);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1825104057);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1073859899);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1704532463);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1919347445);
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct WELL1024a {
+    state: [u32; WELL1024a::R],
+    index: usize,
+}
+
+impl WELL1024a {
+    const R: usize = 32;
+    const M1: usize = 3;
+    const M2: usize = 24;
+    const M3: usize = 10;
+    const CHAR_POLY_DEGREE: usize = 1024;
+
+    pub fn new(seeds: &[u32; WELL1024a::R]) -> WELL1024a {
+        let mut well = WELL1024a {
+            state: *seeds,
+            index: 0,
+        };
+        well_sanitise(&mut well.state);
+        well
+    }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// See [`SplitMix64`] for details of the seed expansion.
+    pub fn from_u64_seed(seed: u64) -> WELL1024a {
+        let mut sm = SplitMix64::new(seed);
+        WELL1024a::new(&core::array::from_fn(|_| sm.next_u32()))
+    }
+    fn next_state(&mut self) {
+        let i = self.index;
+        let s = &mut self.state;
+        let z0 = s[(i + WELL1024a::R - 1) % WELL1024a::R];
+        let z1 = s[i] ^ well_mat0pos(8, s[(i + WELL1024a::M1) % WELL1024a::R]);
+        let z2 = well_mat0neg(19, s[(i + WELL1024a::M2) % WELL1024a::R]) ^ well_mat0neg(14, s[(i + WELL1024a::M3) % WELL1024a::R]);
+        s[i] = z1 ^ z2;
+        s[(i + WELL1024a::R - 1) % WELL1024a::R] = well_mat0neg(11, z0) ^ well_mat0neg(7, z1) ^ well_mat0neg(13, z2);
+        self.index = (i + WELL1024a::R - 1) % WELL1024a::R;
+    }
+    fn current(&self) -> u32 {
+        self.state[self.index]
+    }
+    /// Generate a double value in the range [0, 1), the same as the reference implementation.
+    pub fn next_f64(&mut self) -> f64 {
+        self.next_u32() as f64 * WELL_FACT
+    }
+    fn char_poly() -> &'static gf2poly::Gf2Poly {
+        static CHAR_POLY: OnceLock<gf2poly::Gf2Poly> = OnceLock::new();
+        CHAR_POLY.get_or_init(|| {
+            let mut s = WELL1024a::new(&[1; WELL1024a::R]);
+            let bits: Vec<bool> = (0..2 * WELL1024a::CHAR_POLY_DEGREE).map(|_| s.next_u32() & 1 != 0).collect();
+            gf2poly::Gf2Poly::minimal_polynomial(&bits)
+        })
+    }
+}
+impl LinearStep for WELL1024a {
+    fn zero_state() -> WELL1024a {
+        WELL1024a {
+            state: [0; WELL1024a::R],
+            index: 0,
+        }
+    }
+    fn step(&mut self) {
+        self.next_state();
+    }
+    fn add_state(&mut self, other: &WELL1024a) {
+        for k in 0..WELL1024a::R {
+            self.state[(self.index + k) % WELL1024a::R] ^= other.state[(other.index + k) % WELL1024a::R];
+        }
+    }
+}
+impl RngCore for WELL1024a {
+    fn next_u32(&mut self) -> u32 {
+        self.next_state();
+        self.current()
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl RngJumpAhead for WELL1024a {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        let result = linear_jumpahead(self, n, WELL1024a::char_poly());
+        // The index steps backwards.
+        let n_mod = maths::modulo(n, WELL1024a::R);
+        let index = (self.index + WELL1024a::R - n_mod) % WELL1024a::R;
+        well_set_window(&mut self.state, index, &result.state, result.index);
+        self.index = index;
+    }
+}
+impl Period for WELL1024a {
+    fn period() -> BigUint {
+        &BigUint::from_u64(2).pow(1024) - &BigUint::from_u64(1)
+    }
+}
+
+
+/* SplitMix64 ----------------------------------------------------------------*/
+
+/// SplitMix64 -- Random number generator by Steele, Lea and Flood
+///
+/// The state is a Weyl sequence, x[n]=x[n-1]+0x9E3779B97F4A7C15 mod 2^64, and the output is a
+/// mixing function of the state. It has period 2^64. The output matches Vigna's `splitmix64.c`.
+///
+/// `next_u64()` gives the native 64-bit output. `next_u32()` gives the upper 32 bits of it.
+///
+/// Since the state is a Weyl sequence, jumpahead is simply an addition.
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::SplitMix64::new(1234567);
+///     let r = s.next_u64();
+///     assert_eq!(r, 6457827717110365317);
+///     let r = s.next_u64();
+///     assert_eq!(r, 3203168211198807973);
+///     let r = s.next_u64();
+///     assert_eq!(r, 9817491932198370423);
+///     let r = s.next_u64();
+///     assert_eq!(r, 4593380528125082431);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u64();
+///     assert_eq!(r, 5225725504622326834);
+///
+/// ## Seed expansion
+///
+/// SplitMix64 is mainly provided to expand a single 64-bit seed into the multiple seed values
+/// needed by the other generators. The `from_u64_seed()` constructors of the multi-seed generators
+/// all do this the same way, so that it can be reproduced in other languages:
+///
+/// 1. Seed SplitMix64 with the 64-bit seed.
+/// 2. For each 32-bit seed value of the generator's `new()`, in order of the parameters (or of
+///    the seed array), take the upper 32 bits of the next 64-bit output.
+/// 3. For each 64-bit seed value, take the next 64-bit output.
+/// 4. Pass the seed values to `new()`, which does the usual sanitisation of bad seed values.
+///
+/// For example, for `KISS`:
+///
+///     use rand_core::RngCore;
+///     let mut s = simplerandom::SplitMix64::new(1);
+///     let kiss = simplerandom::KISS::new(s.next_u32(), s.next_u32(), s.next_u32(), s.next_u32());
+///     assert_eq!(kiss, simplerandom::KISS::from_u64_seed(1));
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SplitMix64 {
+    x: u64,
+}
+
+impl SplitMix64 {
+    const GAMMA: u64 = 0x9E3779B97F4A7C15;
+    const CYCLE_LEN: u128 = 1 << 64;
+
+    pub fn new(seed1: u64) -> SplitMix64 {
+        SplitMix64 {
+            x: seed1,
+        }
+    }
+    fn current(&self) -> u64 {
+        let mut z = self.x;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+}
+impl RngCore for SplitMix64 {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.x = self.x.wrapping_add(SplitMix64::GAMMA);
+        self.current()
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl RngJumpAhead for SplitMix64 {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        let n_mod = maths::modulo(n, SplitMix64::CYCLE_LEN) as u64;
+        self.x = self.x.wrapping_add(n_mod.wrapping_mul(SplitMix64::GAMMA));
+    }
+}
+impl Period for SplitMix64 {
+    fn period() -> BigUint {
+        BigUint::from_u128(SplitMix64::CYCLE_LEN)
+    }
+}
+
+
+/* Philox4x32 ----------------------------------------------------------------*/
+
+/// Philox4x32 -- Counter-based random number generator by Salmon et al.
+///
+/// This is Philox4x32-10 from the Random123 library. Each output block is a bijective function
+/// of a 128-bit counter, keyed by a 64-bit key, with 10 rounds of multiplications. The block
+/// function matches the Random123 known-answer tests.
+///
+/// Each block gives 4 32-bit outputs. The i-th output of the generator is word (i mod 4) of the
+/// block for counter i/4, where the counter is split into 4 32-bit words, least significant first.
+/// `at()` gives any output directly, in time order `O(1)`. The generator has period 2^128.
+///
+/// The generator state is just the key and the output position, so jumpahead is just an addition
+/// to the position.
+///
+/// From [[salmon1]](#salmon1).
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::Philox4x32::new(0xa4093822, 0x299f31d0);
+///     let r = s.next_u32();
+///     assert_eq!(r, 0x0e847852);
+///     let r = s.next_u32();
+///     assert_eq!(r, 0xaddb136a);
+///     let r = s.next_u32();
+///     assert_eq!(r, 0x59b5ba7a);
+///     let r = s.next_u32();
+///     assert_eq!(r, 0x7062ac6b);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u32();
+///     assert_eq!(r, s.at(1_000_004));
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Philox4x32 {
+    key: [u32; 2],
+    position: u128,
+}
+
+impl Philox4x32 {
+    const M0: u32 = 0xD2511F53;
+    const M1: u32 = 0xCD9E8D57;
+    const W0: u32 = 0x9E3779B9;
+    const W1: u32 = 0xBB67AE85;
+    const ROUNDS: usize = 10;
+
+    pub fn new(seed1: u32, seed2: u32) -> Philox4x32 {
+        Philox4x32 {
+            key: [seed1, seed2],
+            position: 0,
+        }
+    }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// See [`SplitMix64`] for details of the seed expansion.
+    pub fn from_u64_seed(seed: u64) -> Philox4x32 {
+        let mut sm = SplitMix64::new(seed);
+        Philox4x32::new(sm.next_u32(), sm.next_u32())
+    }
+    /// The Philox4x32-10 block function, for a counter and key.
+    pub fn block(counter: [u32; 4], key: [u32; 2]) -> [u32; 4] {
+        let mut c = counter;
+        let mut k = key;
+        for round in 0..Philox4x32::ROUNDS {
+            if round > 0 {
+                k = [k[0].wrapping_add(Philox4x32::W0), k[1].wrapping_add(Philox4x32::W1)];
+            }
+            let p0 = (Philox4x32::M0 as u64) * (c[0] as u64);
+            let p1 = (Philox4x32::M1 as u64) * (c[2] as u64);
+            c = [((p1 >> 32) as u32) ^ c[1] ^ k[0], p1 as u32, ((p0 >> 32) as u32) ^ c[3] ^ k[1], p0 as u32];
+        }
+        c
+    }
+    /// The output at position `index`, without changing the generator position.
+    pub fn at(&self, index: u128) -> u32 {
+        let counter = index >> 2;
+        let counter_words = [counter as u32, (counter >> 32) as u32, (counter >> 64) as u32, (counter >> 96) as u32];
+        Philox4x32::block(counter_words, self.key)[(index & 3) as usize]
+    }
+}
+impl RngCore for Philox4x32 {
+    fn next_u32(&mut self) -> u32 {
+        let result = self.at(self.position);
+        self.position = self.position.wrapping_add(1);
+        result
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl RngJumpAhead for Philox4x32 {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        // The period is 2^128, which is a modulus of 0 for u128.
+        let n_mod = maths::modulo(n, 0_u128);
+        self.position = self.position.wrapping_add(n_mod);
+    }
+}
+impl Period for Philox4x32 {
+    fn period() -> BigUint {
+        BigUint::from_u64(2).pow(128)
+    }
+}
+
+
+/* Threefry2x32 --------------------------------------------------------------*/
+
+/// Threefry2x32 -- Counter-based random number generator by Salmon et al.
+///
+/// This is Threefry2x32-20 from the Random123 library, based on the Threefish block cipher. Each
+/// output block is a bijective function of a 64-bit counter, keyed by a 64-bit key, with 20 rounds
+/// of additions, rotations and xors. The block function matches the Random123 known-answer tests.
+///
+/// Each block gives 2 32-bit outputs. The i-th output of the generator is word (i mod 2) of the
+/// block for counter i/2, where the counter is split into 2 32-bit words, least significant first.
+/// `at()` gives any output directly, in time order `O(1)`. The generator has period 2^64.
+///
+/// The generator state is just the key and the output position, so jumpahead is just an addition
+/// to the position.
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::Threefry2x32::new(0x13198a2e, 0x03707344);
+///     let r = s.next_u32();
+///     assert_eq!(r, 0x41485429);
+///     let r = s.next_u32();
+///     assert_eq!(r, 0xd158445a);
+///     let r = s.next_u32();
+///     assert_eq!(r, 0x916a90d0);
+///     let r = s.next_u32();
+///     assert_eq!(r, 0x0fc5db96);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u32();
+///     assert_eq!(r, s.at(1_000_004));
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Threefry2x32 {
+    key: [u32; 2],
+    position: u64,
+}
+
+impl Threefry2x32 {
+    const PARITY: u32 = 0x1BD11BDA;
+    const ROTATIONS: [u32; 8] = [13, 15, 26, 6, 17, 29, 16, 24];
+    const ROUNDS: usize = 20;
+    const CYCLE_LEN: u128 = 1 << 64;
+
+    pub fn new(seed1: u32, seed2: u32) -> Threefry2x32 {
+        Threefry2x32 {
+            key: [seed1, seed2],
+            position: 0,
+        }
+    }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// See [`SplitMix64`] for details of the seed expansion.
+    pub fn from_u64_seed(seed: u64) -> Threefry2x32 {
+        let mut sm = SplitMix64::new(seed);
+        Threefry2x32::new(sm.next_u32(), sm.next_u32())
+    }
+    /// The Threefry2x32-20 block function, for a counter and key.
+    pub fn block(counter: [u32; 2], key: [u32; 2]) -> [u32; 2] {
+        let ks = [key[0], key[1], Threefry2x32::PARITY ^ key[0] ^ key[1]];
+        let mut x = [counter[0].wrapping_add(ks[0]), counter[1].wrapping_add(ks[1])];
+        for round in 0..Threefry2x32::ROUNDS {
+            x[0] = x[0].wrapping_add(x[1]);
+            x[1] = x[1].rotate_left(Threefry2x32::ROTATIONS[round % 8]) ^ x[0];
+            if round % 4 == 3 {
+                // Key injection
+                let s = round / 4 + 1;
+                x[0] = x[0].wrapping_add(ks[s % 3]);
+                x[1] = x[1].wrapping_add(ks[(s + 1) % 3]).wrapping_add(s as u32);
+            }
+        }
+        x
+    }
+    /// The output at position `index`, without changing the generator position.
+    pub fn at(&self, index: u64) -> u32 {
+        let counter = index >> 1;
+        Threefry2x32::block([counter as u32, (counter >> 32) as u32], self.key)[(index & 1) as usize]
+    }
+}
+impl RngCore for Threefry2x32 {
+    fn next_u32(&mut self) -> u32 {
+        let result = self.at(self.position);
+        self.position = self.position.wrapping_add(1);
+        result
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl RngJumpAhead for Threefry2x32 {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        let n_mod = maths::modulo(n, Threefry2x32::CYCLE_LEN) as u64;
+        self.position = self.position.wrapping_add(n_mod);
+    }
+}
+impl Period for Threefry2x32 {
+    fn period() -> BigUint {
+        BigUint::from_u128(Threefry2x32::CYCLE_LEN)
+    }
+}
+
+
+/* Mwc -----------------------------------------------------------------------*/
+
+/// Mwc -- Lag-r "Multiply-with-carry" random number generator, by Marsaglia
+///
+/// This is a generalisation of multiply-with-carry to a lag of `R` 32-bit values, with multiplier
+/// `A`. The generator keeps `R` previous values x and a carry c, and calculates:
+///
+/// t = A x[n-R] + c[n-1],
+/// x[n] = t mod b,
+/// c[n] = t / b.
+///
+/// With `COMPLEMENTARY` false, it is a plain MWC with base b = 2^32. With `COMPLEMENTARY` true, it
+/// is a complementary MWC (CMWC) with base b = 2^32-1, and the output is the complement
+/// x[n] = (b-1) - (t mod b). Type aliases are provided for Marsaglia's generators [`MWC256`] and
+/// [`CMWC4096`].
+///
+/// The generator is equivalent to a multiplicative LCG with the prime modulus m = A b^R - 1 (MWC)
+/// or m = A b^R + 1 (CMWC), and multiplier b^-1 mod m. The period is the order of b modulo m,
+/// which is about 2^8222 for `MWC256` and about 2^131086 for `CMWC4096`. Jumpahead is done by
+/// converting the state to the equivalent LCG value, multiplying by b^-n mod m, and converting
+/// back. This uses the big integer arithmetic in [`biguint`]. Since b^-R is just ±A modulo m, the
+/// multiplier stays small for moderate jumps, but very large jumps need multiplications of R-word
+/// numbers.
+///
+/// The seed is the `R` initial values, oldest first, plus the initial carry. The carry is
+/// reduced modulo `A`. For CMWC, any value 2^32-1 (which is not a valid base 2^32-1 digit) is
+/// reduced to 0. For MWC, the two states that are equivalent to 0 modulo m (all zeros with carry
+/// 0, and all ones with carry `A`-1) are fixed points, so for those the seed values are inverted.
+///
+/// `R` must be at least 1, and `A` at least 2. These are checked when the type is built, so
+/// invalid parameters fail to compile:
+///
+/// ```compile_fail
+/// let s = simplerandom::Mwc::<0, 4, false>::new(&[1, 2, 3, 4], 5);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Mwc<const A: u32, const R: usize, const COMPLEMENTARY: bool> {
+    q: [u32; R],
+    c: u32,
+    index: usize,
+}
+
+/// Marsaglia's MWC256, a lag-256 multiply-with-carry generator with period about 2^8222.
+///
+/// From [[mars3]](#mars3). Seeded with the same values and carry, the output matches Marsaglia's C
+/// code.
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::MWC256::from_u64_seed(1);
+///     let r = s.next_u32();
+///     assert_eq!(r, 3413424498);
+///     let r = s.next_u32();
+///     assert_eq!(r, 125060409);
+///     let r = s.next_u32();
+///     assert_eq!(r, 2805489716);
+///     let r = s.next_u32();
+///     assert_eq!(r, 1486621735);
+///     s.jumpahead(1_000_000_000_000_i64);
+///     let r = s.next_u32();
+///     assert_eq!(r, 3708652991);
+pub type MWC256 = Mwc<809430660, 256, false>;
+
+/// Marsaglia's CMWC4096, a lag-4096 complementary multiply-with-carry generator with period about
+/// 2^131086.
+///
+/// From [[mars3]](#mars3). Seeded with the same values and a carry less than 18782, the output
+/// matches Marsaglia's C code. His example seeds the carry with 362436, which is reduced modulo
+/// 18782 here, so the first output differs, although the outputs after it are the same. Also, his
+/// code calculates t mod (2^32-1) with a shortcut that gives 2^32-1 rather than 0 when t is a
+/// multiple of 2^32-1. This happens about once in 2^32 outputs. This generator reduces exactly,
+/// so from that point the sequences differ.
+///
+///     use rand_core::RngCore;
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::CMWC4096::from_u64_seed(1);
+///     let r = s.next_u32();
+///     assert_eq!(r, 3609894287);
+///     let r = s.next_u32();
+///     assert_eq!(r, 3122574744);
+///     let r = s.next_u32();
+///     assert_eq!(r, 2689847462);
+///     let r = s.next_u32();
+///     assert_eq!(r, 194060104);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u32();
+///     assert_eq!(r, 2675164205);
+pub type CMWC4096 = Mwc<18782, 4096, true>;
+
+impl<const A: u32, const R: usize, const COMPLEMENTARY: bool> Mwc<A, R, COMPLEMENTARY> {
+    const BASE: u64 = if COMPLEMENTARY { 0xFFFFFFFF } else { 1 << 32 };
+    /// Check of the type parameters. It is evaluated when `new()` is instantiated, so invalid
+    /// parameters are a build error.
+    const VALID_PARAMETERS: () = {
+        assert!(R >= 1, "Mwc lag must be at least 1");
+        assert!(A >= 2, "Mwc multiplier must be at least 2");
+    };
+
+    pub fn new(seed: &[u32; R], carry: u32) -> Mwc<A, R, COMPLEMENTARY> {
+        let () = Mwc::<A, R, COMPLEMENTARY>::VALID_PARAMETERS;
+        let mut result = Mwc {
+            q: *seed,
+            c: carry,
+            index: R - 1,
+        };
+        result.sanitise();
+        result
+    }
+    /// Seed the generator from a single 64-bit value, expanded by `SplitMix64`.
+    ///
+    /// The `R` values are seeded first, then the carry. See [`SplitMix64`] for details of the seed
+    /// expansion.
+    pub fn from_u64_seed(seed: u64) -> Mwc<A, R, COMPLEMENTARY> {
+        let mut sm = SplitMix64::new(seed);
+        let mut q = [0_u32; R];
+        for q_i in q.iter_mut() {
+            *q_i = sm.next_u32();
+        }
+        Mwc::new(&q, sm.next_u32())
+    }
+    fn sanitise(&mut self) {
+        self.c %= A;
+        if COMPLEMENTARY {
+            for q_i in self.q.iter_mut() {
+                if *q_i as u64 >= Mwc::<A, R, COMPLEMENTARY>::BASE {
+                    *q_i = 0;
+                }
+            }
+        } else if (self.c == 0 && self.q.iter().all(|&x| x == 0))
+            || (self.c == A - 1 && self.q.iter().all(|&x| x == u32::MAX))
+        {
+            for q_i in self.q.iter_mut() {
+                *q_i = !*q_i;
+            }
+        }
+    }
+    fn current(&self) -> u32 {
+        self.q[self.index]
+    }
+    /// The modulus m of the equivalent LCG.
+    fn lcg_modulus() -> BigUint {
+        let base = BigUint::from_u64(Mwc::<A, R, COMPLEMENTARY>::BASE);
+        let a_b_r = &base.pow(R as u32) * &BigUint::from_u64(A as u64);
+        if COMPLEMENTARY {
+            &a_b_r + &BigUint::from_u64(1)
+        } else {
+            &a_b_r - &BigUint::from_u64(1)
+        }
+    }
+    /// The values in base b, oldest first, as one number: sum of x[n-R+i] b^i.
+    fn lcg_digits_value(xs: &[u32; R]) -> BigUint {
+        let base = BigUint::from_u64(Mwc::<A, R, COMPLEMENTARY>::BASE);
+        let mut result = BigUint::zero();
+        for x in xs.iter().rev() {
+            result = &(&result * &base) + &BigUint::from_u64(*x as u64);
+        }
+        result
+    }
+    /// The value of the equivalent LCG: T = c + A X for MWC, or T = c + A X + 1 for CMWC, where X
+    /// is the values as one number in base b.
+    fn lcg_state(&self) -> BigUint {
+        let mut xs = [0_u32; R];
+        for (i, x) in xs.iter_mut().enumerate() {
+            *x = self.q[(self.index + 1 + i) % R];
+        }
+        let mut result = &Mwc::<A, R, COMPLEMENTARY>::lcg_digits_value(&xs) * &BigUint::from_u64(A as u64);
+        result = &result + &BigUint::from_u64(self.c as u64 + COMPLEMENTARY as u64);
+        result
+    }
+    /// Set the state from the value of the equivalent LCG.
+    ///
+    /// Each value is recovered by stepping the LCG backwards: x[n-1] = floor(b T[n] / m), and
+    /// T[n-1] = b T[n] mod m.
+    fn set_lcg_state(&mut self, t: &BigUint, m: &BigUint) {
+        let base = BigUint::from_u64(Mwc::<A, R, COMPLEMENTARY>::BASE);
+        let mut xs = [0_u32; R];
+        let mut t_work = t.clone();
+        for x in xs.iter_mut().rev() {
+            let (x_big, t_prev) = (&t_work * &base).div_rem(m);
+            *x = x_big.to_u64().unwrap() as u32;
+            t_work = t_prev;
+        }
+        let a_x = &Mwc::<A, R, COMPLEMENTARY>::lcg_digits_value(&xs) * &BigUint::from_u64(A as u64);
+        let c = &(t - &a_x) - &BigUint::from_u64(COMPLEMENTARY as u64);
+        self.c = c.to_u64().unwrap() as u32;
+        for (i, x) in xs.iter().enumerate() {
+            self.q[(self.index + 1 + i) % R] = *x;
+        }
+    }
+}
+impl<const A: u32, const R: usize, const COMPLEMENTARY: bool> RngCore for Mwc<A, R, COMPLEMENTARY> {
+    fn next_u32(&mut self) -> u32 {
+        self.index = (self.index + 1) % R;
+        let t = (A as u64) * (self.q[self.index] as u64) + (self.c as u64);
+        if COMPLEMENTARY {
+            let base = Mwc::<A, R, COMPLEMENTARY>::BASE;
+            self.c = (t / base) as u32;
+            self.q[self.index] = (base - 1 - t % base) as u32;
+        } else {
+            self.c = (t >> 32) as u32;
+            self.q[self.index] = t as u32;
+        }
+        self.current()
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl<const A: u32, const R: usize, const COMPLEMENTARY: bool> RngJumpAhead for Mwc<A, R, COMPLEMENTARY> {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        // Write n = q R + s, with 0 <= s < R. Step s times, which also gives the correct index. Then
+        // jump q R steps, which in the equivalent LCG is a multiplication by b^(-R q). For MWC
+        // b^-R = A and b^R = A^-1, while for CMWC b^-R = -A and b^R = -A^-1, modulo m.
+        let s = maths::modulo(n, R as u128);
+        let n_abs: u128 = NumCast::from(maths::abs_as_unsigned(n)).unwrap_or(0);
+        let q_abs = if n >= N::ZERO { n_abs / R as u128 } else { (n_abs + s) / R as u128 };
+        for _ in 0..s {
+            self.next_u32();
+        }
+        if q_abs != 0 {
+            let m = Mwc::<A, R, COMPLEMENTARY>::lcg_modulus();
+            // For CMWC, b^R already includes the sign, so only the forward multiplier A needs to be
+            // negated.
+            let mult = if n >= N::ZERO {
+                let mult = BigUint::from_u64(A as u64).pow_mod(q_abs, &m);
+                if COMPLEMENTARY && q_abs % 2 != 0 {
+                    &m - &mult
+                } else {
+                    mult
+                }
+            } else {
+                BigUint::from_u64(Mwc::<A, R, COMPLEMENTARY>::BASE).pow(R as u32).pow_mod(q_abs, &m)
+            };
+            let t = self.lcg_state().mul_mod(&mult, &m);
+            self.set_lcg_state(&t, &m);
+        }
+    }
+}
+
+
+/* SubtractWithBorrow --------------------------------------------------------*/
+
+/// SubtractWithBorrow -- "Subtract-with-borrow" random number generator by Marsaglia and Zaman
+///
+/// This generates `W`-bit words, with long lag `R` and short lag `S`:
+///
+/// x[n] = (x[n-S] - x[n-R] - c[n-1]) mod 2^W,
+/// c[n] = 1 if x[n-S] - x[n-R] - c[n-1] < 0, else 0.
+///
+/// It is the same as C++ `std::subtract_with_carry_engine`, including its seeding from a single
+/// value by an LCG. Type aliases [`Ranlux24Base`] and [`Ranlux48Base`] match C++
+/// `std::ranlux24_base` and `std::ranlux48_base`. The words on their own have poor statistical
+/// properties, so this is mainly the base generator for [`Ranlux`].
+///
+/// `next_word()` gives one `W`-bit word. `next_u32()` and `next_u64()` concatenate as many words as
+/// are needed, first word most significant, and return the lower bits. Jumpahead counts words,
+/// like `discard()` in C++.
+///
+/// The generator is equivalent to a multiplicative LCG with modulus m = b^R - b^S + 1, with
+/// b = 2^W, and multiplier b^-1 mod m [[tezuka1]](#tezuka1). Jumpahead is done by converting the
+/// state to the equivalent LCG value, multiplying by b^-n mod m, and converting back. The modulus
+/// is 576 bits for both C++ types, so this is done with the big integer arithmetic in [`biguint`].
+///
+/// From [[marzam1]](#marzam1).
+///
+///     use simplerandom::RngJumpAhead;
+///     // The 10000th output of a default-constructed C++ std::ranlux24_base.
+///     let mut s = simplerandom::Ranlux24Base::new(simplerandom::SWB_DEFAULT_SEED);
+///     s.jumpahead(9999);
+///     let r = s.next_word();
+///     assert_eq!(r, 7937952);
+///
+/// `W` must be from 1 to 63, and the lags must satisfy 0 < `S` < `R`. These are checked when the
+/// type is built, so invalid parameters fail to compile:
+///
+/// ```compile_fail
+/// let s = simplerandom::SubtractWithBorrow::<24, 24, 10>::new(1);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SubtractWithBorrow<const W: u32, const S: usize, const R: usize> {
+    x: [u64; R],
+    c: u64,
+    /// Index of the oldest word x[n-R], which is the next to be replaced.
+    index: usize,
+}
+
+/// Same as C++ `std::ranlux24_base`.
+pub type Ranlux24Base = SubtractWithBorrow<24, 10, 24>;
+/// Same as C++ `std::ranlux48_base`.
+pub type Ranlux48Base = SubtractWithBorrow<48, 5, 12>;
+
+/// The default seed of the C++ `std::subtract_with_carry_engine`. A seed of 0 is also replaced by
+/// this.
+pub const SWB_DEFAULT_SEED: u32 = 19780503;
+
+impl<const W: u32, const S: usize, const R: usize> SubtractWithBorrow<W, S, R> {
+    const MASK: u64 = u64::MAX >> (64 - W);
+    const SEED_LCG_M: u32 = 40014;
+    const SEED_LCG_MOD: u32 = 2147483563;
+    /// Check of the type parameters. It is evaluated when `new()` is instantiated, so invalid
+    /// parameters are a build error.
+    const VALID_PARAMETERS: () = {
+        assert!(0 < W && W < 64, "SubtractWithBorrow word size must be 1 to 63 bits");
+        assert!(0 < S && S < R, "SubtractWithBorrow lags must satisfy 0 < S < R");
+    };
+
+    /// Seed the generator the same way as C++ `std::subtract_with_carry_engine::seed()`.
+    ///
+    /// The words are generated by the LCG z[n] = 40014 z[n-1] mod 2147483563, taking as many 32-bit
+    /// LCG outputs per word as are needed, least significant first. The state can't be one of the
+    /// two "bad" states (all words zero with borrow 0, or all words 2^W-1 with borrow 1) which are
+    /// fixed points.
+    pub fn new(seed1: u32) -> SubtractWithBorrow<W, S, R> {
+        let () = SubtractWithBorrow::<W, S, R>::VALID_PARAMETERS;
+        let seed1 = if seed1 == 0 { SWB_DEFAULT_SEED } else { seed1 };
+        let lcg_mod = SubtractWithBorrow::<W, S, R>::SEED_LCG_MOD;
+        let mut z = seed1 % lcg_mod;
+        if z == 0 {
+            z = 1;
+        }
+        let mut x = [0_u64; R];
+        for x_i in x.iter_mut() {
+            let mut word = 0_u64;
+            for j in 0..W.div_ceil(32) {
+                z = maths::mul_mod(z, SubtractWithBorrow::<W, S, R>::SEED_LCG_M, lcg_mod);
+                word |= (z as u64) << (32 * j);
+            }
+            *x_i = word & SubtractWithBorrow::<W, S, R>::MASK;
+        }
+        let c = if x[R - 1] == 0 { 1 } else { 0 };
+        SubtractWithBorrow { x, c, index: 0 }
+    }
+    /// Generate the next `W`-bit word.
+    pub fn next_word(&mut self) -> u64 {
+        let short_index = (self.index + R - S) % R;
+        let (result, borrow) = self.x[short_index].overflowing_sub(self.x[self.index] + self.c);
+        self.c = borrow as u64;
+        self.x[self.index] = result & SubtractWithBorrow::<W, S, R>::MASK;
+        let result = self.x[self.index];
+        self.index = (self.index + 1) % R;
+        result
+    }
+    /// The modulus m = b^R - b^S + 1 of the equivalent LCG.
+    fn lcg_modulus() -> BigUint {
+        let base = BigUint::from_u64(1 << W);
+        &(&base.pow(R as u32) - &base.pow(S as u32)) + &BigUint::from_u64(1)
+    }
+    /// The words in base b, as one number: sum of xs[i] b^i.
+    fn lcg_digits_value(xs: &[u64]) -> BigUint {
+        let base = BigUint::from_u64(1 << W);
+        let mut result = BigUint::zero();
+        for x in xs.iter().rev() {
+            result = &(&result * &base) + &BigUint::from_u64(*x);
+        }
+        result
+    }
+    /// The value of the equivalent LCG, T = c + X - floor(X / b^(R-S)), where X is the words,
+    /// oldest first, as one number in base b.
+    fn lcg_state(&self) -> BigUint {
+        let mut xs = [0_u64; R];
+        for (i, x) in xs.iter_mut().enumerate() {
+            *x = self.x[(self.index + i) % R];
+        }
+        let x_all = SubtractWithBorrow::<W, S, R>::lcg_digits_value(&xs);
+        let x_upper = SubtractWithBorrow::<W, S, R>::lcg_digits_value(&xs[R - S..]);
+        &(&x_all - &x_upper) + &BigUint::from_u64(self.c)
+    }
+    /// Set the state from the value of the equivalent LCG.
+    ///
+    /// Each word is recovered by stepping the LCG backwards: x[n-1] = floor(b T[n] / m), and
+    /// T[n-1] = b T[n] mod m.
+    fn set_lcg_state(&mut self, t: &BigUint, m: &BigUint) {
+        let base = BigUint::from_u64(1 << W);
+        let mut xs = [0_u64; R];
+        let mut t_work = t.clone();
+        for x in xs.iter_mut().rev() {
+            let (x_big, t_prev) = (&t_work * &base).div_rem(m);
+            *x = x_big.to_u64().unwrap();
+            t_work = t_prev;
+        }
+        let x_all = SubtractWithBorrow::<W, S, R>::lcg_digits_value(&xs);
+        let x_upper = SubtractWithBorrow::<W, S, R>::lcg_digits_value(&xs[R - S..]);
+        self.c = (t - &(&x_all - &x_upper)).to_u64().unwrap();
+        for (i, x) in xs.iter().enumerate() {
+            self.x[(self.index + i) % R] = *x;
+        }
+    }
+    /// Jump ahead (or back, if `backward`) by `n_abs * times` words.
+    ///
+    /// The product can be larger than a u128, which is needed for the block jumps of [`Ranlux`].
+    fn jumpahead_times(&mut self, n_abs: u128, backward: bool, times: u128) {
+        let m = SubtractWithBorrow::<W, S, R>::lcg_modulus();
+        let base = BigUint::from_u64(1 << W);
+        // The LCG steps forward by multiplying by b^-1 = m - (b^(R-1) - b^(S-1)), and back by
+        // multiplying by b.
+        let mult_base = if backward {
+            base
+        } else {
+            &m - &(&base.pow(R as u32 - 1) - &base.pow(S as u32 - 1))
+        };
+        let mult = mult_base.pow_mod(n_abs, &m).pow_mod(times, &m);
+        let t = self.lcg_state().mul_mod(&mult, &m);
+
+        let index_step = ((n_abs % R as u128) * (times % R as u128) % R as u128) as usize;
+        self.index = if backward {
+            (self.index + R - index_step) % R
+        } else {
+            (self.index + index_step) % R
+        };
+        self.set_lcg_state(&t, &m);
+    }
+}
+impl<const W: u32, const S: usize, const R: usize> RngCore for SubtractWithBorrow<W, S, R> {
+    fn next_u32(&mut self) -> u32 {
+        concat_words(W, 32, || self.next_word()) as u32
+    }
+    fn next_u64(&mut self) -> u64 {
+        concat_words(W, 64, || self.next_word())
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl<const W: u32, const S: usize, const R: usize> RngJumpAhead for SubtractWithBorrow<W, S, R> {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        let n_abs: u128 = NumCast::from(maths::abs_as_unsigned(n)).unwrap_or(0);
+        self.jumpahead_times(n_abs, n < N::ZERO, 1);
+    }
+}
+
+
+/* Ranlux --------------------------------------------------------------------*/
+
+/// Ranlux -- Lüscher's "luxury" random number generator
+///
+/// This is a [`SubtractWithBorrow`] generator, of which only `USED` words out of every block of
+/// `P` are output, and the rest are discarded. Lüscher showed that discarding enough words
+/// decorrelates the outputs of the SWB generator, by the chaotic properties of its dynamics
+/// [[luscher1]](#luscher1). The "luxury level" is the choice of `P`.
+///
+/// The type aliases [`Ranlux24`] and [`Ranlux48`] are the same as C++ `std::ranlux24` and
+/// `std::ranlux48`, that is, `std::discard_block_engine` with the C++ base generators. Lüscher's
+/// original RANLUX uses the 24-bit base generator, with all 24 words of a block used, and `P` of
+/// 24, 48, 97, 223 or 389 for luxury levels 0 to 4. For example, `Ranlux<24, 10, 24, 389, 24>` is
+/// luxury level 4.
+///
+/// `next_word()`, `next_u32()` and `next_u64()` are as for [`SubtractWithBorrow`], and jumpahead
+/// counts output words.
+///
+/// Jumpahead by whole blocks is done by jumping the base generator, and the remainder by single
+/// steps. The discard is done straight after the last used word of each block, rather than before
+/// the next word as in C++. The outputs are the same, but that way the state of a generator which
+/// has jumped ahead is the same as one which has stepped.
+///
+///     use simplerandom::RngJumpAhead;
+///     // The 10000th output of a default-constructed C++ std::ranlux24.
+///     let mut s = simplerandom::Ranlux24::new(simplerandom::SWB_DEFAULT_SEED);
+///     s.jumpahead(9999);
+///     let r = s.next_word();
+///     assert_eq!(r, 9901578);
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Ranlux<const W: u32, const S: usize, const R: usize, const P: usize, const USED: usize> {
+    swb: SubtractWithBorrow<W, S, R>,
+    /// Number of words output so far in the current block.
+    n: usize,
+}
+
+/// Same as C++ `std::ranlux24`.
+pub type Ranlux24 = Ranlux<24, 10, 24, 223, 23>;
+/// Same as C++ `std::ranlux48`.
+pub type Ranlux48 = Ranlux<48, 5, 12, 389, 11>;
+
+impl<const W: u32, const S: usize, const R: usize, const P: usize, const USED: usize> Ranlux<W, S, R, P, USED> {
+    /// Seed the base generator, as for [`SubtractWithBorrow::new()`].
+    pub fn new(seed1: u32) -> Ranlux<W, S, R, P, USED> {
+        Ranlux {
+            swb: SubtractWithBorrow::new(seed1),
+            n: 0,
+        }
+    }
+    /// Generate the next `W`-bit word.
+    pub fn next_word(&mut self) -> u64 {
+        let result = self.swb.next_word();
+        self.n += 1;
+        if self.n >= USED {
+            for _ in USED..P {
+                self.swb.next_word();
+            }
+            self.n = 0;
+        }
+        result
+    }
+}
+impl<const W: u32, const S: usize, const R: usize, const P: usize, const USED: usize> RngCore for Ranlux<W, S, R, P, USED> {
+    fn next_u32(&mut self) -> u32 {
+        concat_words(W, 32, || self.next_word()) as u32
+    }
+    fn next_u64(&mut self) -> u64 {
+        concat_words(W, 64, || self.next_word())
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl<const W: u32, const S: usize, const R: usize, const P: usize, const USED: usize> RngJumpAhead for Ranlux<W, S, R, P, USED> {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        // Write n = q USED + s, with 0 <= s < USED. Jump the base generator by q blocks, then step
+        // s times.
+        let s = maths::modulo(n, USED as u128);
+        let n_abs: u128 = NumCast::from(maths::abs_as_unsigned(n)).unwrap_or(0);
+        let q_abs = if n >= N::ZERO { n_abs / USED as u128 } else { (n_abs + s) / USED as u128 };
+        if q_abs != 0 {
+            self.swb.jumpahead_times(q_abs, n < N::ZERO, P as u128);
+        }
+        for _ in 0..s {
+            self.next_word();
+        }
+    }
+}
+
+
+/* Xorshift16 ----------------------------------------------------------------*/
+
+/// Xorshift16 -- 16-bit 3-shift-register random number generator, for 8- and 16-bit
+/// microcontrollers
+///
+/// This is the same type of generator as SHR3, but with a 16-bit state:
+/// y[n]=y[n-1](I+L^7)(I+R^9)(I+L^8).
+/// It has period 2^16-1 for any seed. A zero seed is replaced by 0xFFFF. The shift of 8 is just a
+/// byte move on an 8-bit processor.
+///
+/// `next_u16()` outputs the state, and `next_u8()` its upper 8 bits. `next_u32()` concatenates the
+/// states of two steps, first step most significant. Like SHR3, it fails tests related to binary
+/// rank.
+///
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::Xorshift16::new(1);
+///     let r = s.next_u16();
+///     assert_eq!(r, 33153);
+///     let r = s.next_u16();
+///     assert_eq!(r, 24609);
+///     let r = s.next_u16();
+///     assert_eq!(r, 59801);
+///     let r = s.next_u8();
+///     assert_eq!(r, 46);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u16();
+///     assert_eq!(r, 58644);
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Xorshift16 {
+    x: u16,
+}
+
+impl Xorshift16 {
+    const CYCLE_LEN: u16 = 0xFFFF;
+
+    pub fn new(seed1: u16) -> Xorshift16 {
+        let mut result = Xorshift16 {
+            x: seed1,
+        };
+        result.sanitise();
+        result
+    }
+    fn sanitise(&mut self) {
+        if self.x == 0 {
+            self.x = 0xFFFF;
+        }
+    }
+    /// Transition matrix of the step, for jumpahead.
+    fn matrix() -> &'static BitColumnMatrix16 {
+        static MATRIX: OnceLock<BitColumnMatrix16> = OnceLock::new();
+        MATRIX.get_or_init(|| BitColumnMatrix16::from_linear_fn(|x| Xorshift16 { x }.next_u16()))
+    }
+    /// Generate the next 16-bit value, which is the new state.
+    pub fn next_u16(&mut self) -> u16 {
+        let mut x = self.x;
+
+        x ^= x << 7;
+        x ^= x >> 9;
+        x ^= x << 8;
+        self.x = x;
+
+        x
+    }
+    /// Generate the next 8-bit value, which is the upper 8 bits of the new state.
+    pub fn next_u8(&mut self) -> u8 {
+        (self.next_u16() >> 8) as u8
+    }
+}
+impl RngCore for Xorshift16 {
+    fn next_u32(&mut self) -> u32 {
+        concat_words(16, 32, || self.next_u16() as u64) as u32
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl RngJumpAhead for Xorshift16 {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        let n_mod = maths::modulo(n, Xorshift16::CYCLE_LEN);
+        let xorshift16_mult = Xorshift16::matrix().clone().pow(n_mod);
+        self.x = xorshift16_mult.dot_vec(self.x);
+    }
+}
+impl Period for Xorshift16 {
+    fn period() -> BigUint {
+        BigUint::from_u64(Xorshift16::CYCLE_LEN as u64)
+    }
+}
+
+
+/* MWC8 ----------------------------------------------------------------------*/
+
+/// MWC8 -- 8-bit "Multiply-with-carry" random number generator, for 8- and 16-bit
+/// microcontrollers
+///
+/// This is a lag-1 multiply-with-carry generator in base 2^8, with a 16-bit state made of an 8-bit
+/// value x and an 8-bit carry c:
+///
+/// t = 249 x[n-1] + c[n-1],
+/// x[n] = t mod 2^8,
+/// c[n] = t / 2^8.
+///
+/// The calculation only needs an 8 by 8 bit multiply, such as the AVR `MUL` instruction. It is
+/// equivalent to a multiplicative LCG with modulus m = 249 * 2^8 - 1 = 63743, which is prime. The
+/// multiplier 249 was chosen as the largest for which 2^8 has the maximum order (m-1)/2 modulo m,
+/// so the period is 31871 for any seed. Seeding reduces the seed modulo m, and a zero state is
+/// replaced by a non-zero value, in the same way as for MWC2.
+///
+/// `next_u8()` outputs x. `next_u16()` and `next_u32()` concatenate the outputs of several steps,
+/// first step most significant.
+///
+///     use simplerandom::RngJumpAhead;
+///     let mut s = simplerandom::MWC8::new(1);
+///     let r = s.next_u8();
+///     assert_eq!(r, 249);
+///     let r = s.next_u8();
+///     assert_eq!(r, 49);
+///     let r = s.next_u8();
+///     assert_eq!(r, 155);
+///     let r = s.next_u16();
+///     assert_eq!(r, 62449);
+///     s.jumpahead(1_000_000);
+///     let r = s.next_u8();
+///     assert_eq!(r, 239);
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MWC8 {
+    mwc: u16,
+}
+
+impl MWC8 {
+    const M: u16 = 249;
+    const MOD: u16 = (MWC8::M << 8) - 1;
+    const CYCLE_LEN: u16 = (MWC8::M << 8) / 2 - 1;
+
+    pub fn new(seed1: u16) -> MWC8 {
+        let mut result = MWC8 {
+            mwc: seed1,
+        };
+        result.sanitise();
+        result
+    }
+    fn sanitise(&mut self) {
+        self.mwc = mwc_sanitise(self.mwc, MWC8::MOD);
+    }
+    /// Generate the next 8-bit value.
+    pub fn next_u8(&mut self) -> u8 {
+        self.mwc = mwc_next(self.mwc, MWC8::M);
+        self.mwc as u8
+    }
+    /// Generate the next 16-bit value, from two 8-bit values.
+    pub fn next_u16(&mut self) -> u16 {
+        concat_words(8, 16, || self.next_u8() as u64) as u16
+    }
+}
+impl RngCore for MWC8 {
+    fn next_u32(&mut self) -> u32 {
+        concat_words(8, 32, || self.next_u8() as u64) as u32
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl RngJumpAhead for MWC8 {
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        let n_mod = maths::modulo(n, MWC8::CYCLE_LEN);
+        self.mwc = maths::mul_mod(maths::pow_mod(MWC8::M, n_mod, MWC8::MOD), self.mwc, MWC8::MOD);
+    }
+}
+impl Period for MWC8 {
+    fn period() -> BigUint {
+        BigUint::from_u64(MWC8::CYCLE_LEN as u64)
+    }
+}
+
+
+/* Combined ------------------------------------------------------------------*/
+
+/// Operation to combine the outputs of two generators, for [`Combined`].
+pub trait CombineOp {
+    fn combine(a: u32, b: u32) -> u32;
+}
+
+/// Combine outputs by wrapping addition.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CombineAdd;
+
+impl CombineOp for CombineAdd {
+    fn combine(a: u32, b: u32) -> u32 {
+        a.wrapping_add(b)
+    }
+}
+
+/// Combine outputs by exclusive-or.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CombineXor;
+
+impl CombineOp for CombineXor {
+    fn combine(a: u32, b: u32) -> u32 {
+        a ^ b
+    }
+}
+
+/// Combined -- Combination of two random number generators
+///
+/// The output of `next_u32()` is the outputs of the two generators `A` and `B`, combined by the
+/// operation `Op`, which is [`CombineAdd`] or [`CombineXor`]. This is how KISS and the combined
+/// LFSR generators are built. Combining generators of different types can hide the weaknesses of
+/// each, and the period is the least common multiple of the periods of the parts.
+///
+/// Jumpahead jumps each part. [`Period`] is implemented when both parts implement it.
+///
+/// More than two generators can be combined by nesting. The aliases [`Combined3`] and
+/// [`Combined4`] do that for one operation, and can be created from a tuple of the parts. Nesting
+/// with different operations gives other combinations, for example `KISS` is
+/// `Combined<Combined<MWC2, Cong, CombineXor>, SHR3, CombineAdd>`.
+///
+///     use rand_core::RngCore;
+///     use simplerandom::{CombineAdd, Combined, RngJumpAhead};
+///     let mut s = Combined::<_, _, CombineAdd>::new(simplerandom::LFSR113::new(1, 2, 3, 4), simplerandom::MWC2::new(1, 2));
+///     let mut lfsr113 = simplerandom::LFSR113::new(1, 2, 3, 4);
+///     let mut mwc2 = simplerandom::MWC2::new(1, 2);
+///     let r = s.next_u32();
+///     assert_eq!(r, lfsr113.next_u32().wrapping_add(mwc2.next_u32()));
+///     s.jumpahead(1_000_000);
+///     lfsr113.jumpahead(1_000_000);
+///     mwc2.jumpahead(1_000_000);
+///     let r = s.next_u32();
+///     assert_eq!(r, lfsr113.next_u32().wrapping_add(mwc2.next_u32()));
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Combined<A, B, Op> {
+    a: A,
+    b: B,
+    op: PhantomData<Op>,
+}
+
+/// Combination of three random number generators, with one operation.
+pub type Combined3<A, B, C, Op> = Combined<Combined<A, B, Op>, C, Op>;
+/// Combination of four random number generators, with one operation.
+pub type Combined4<A, B, C, D, Op> = Combined<Combined3<A, B, C, Op>, D, Op>;
+
+impl<A, B, Op> Combined<A, B, Op> {
+    pub fn new(a: A, b: B) -> Combined<A, B, Op> {
+        Combined {
+            a,
+            b,
+            op: PhantomData,
+        }
+    }
+    /// The two parts.
+    pub fn parts(&self) -> (&A, &B) {
+        (&self.a, &self.b)
+    }
+}
+impl<A, B, Op> From<(A, B)> for Combined<A, B, Op> {
+    fn from(parts: (A, B)) -> Combined<A, B, Op> {
+        Combined::new(parts.0, parts.1)
+    }
+}
+impl<A, B, C, Op> From<(A, B, C)> for Combined3<A, B, C, Op> {
+    fn from(parts: (A, B, C)) -> Combined3<A, B, C, Op> {
+        Combined::new(Combined::new(parts.0, parts.1), parts.2)
+    }
+}
+impl<A, B, C, D, Op> From<(A, B, C, D)> for Combined4<A, B, C, D, Op> {
+    fn from(parts: (A, B, C, D)) -> Combined4<A, B, C, D, Op> {
+        Combined::new((parts.0, parts.1, parts.2).into(), parts.3)
+    }
+}
+impl<A, B, Op> RngCore for Combined<A, B, Op>
+    where A: RngCore, B: RngCore, Op: CombineOp
+{
+    fn next_u32(&mut self) -> u32 {
+        Op::combine(self.a.next_u32(), self.b.next_u32())
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+impl<A, B, Op> RngJumpAhead for Combined<A, B, Op>
+    where A: RngJumpAhead, B: RngJumpAhead
+{
+    fn jumpahead<N>(&mut self, n: N)
+        where N: maths::IntTypes
+    {
+        self.a.jumpahead(n);
+        self.b.jumpahead(n);
+    }
+}
+impl<A, B, Op> Period for Combined<A, B, Op>
+    where A: Period, B: Period
+{
+    fn period() -> BigUint {
+        A::period().lcm(&B::period())
+    }
+}
@@ -2,6 +2,7 @@
 
 use rand_core::RngCore;
 use simplerandom::RngJumpAhead;
+use simplerandom::stats;
 //use rand::Rng;
 
 fn test_new_and_next_u32() {
@@ -37,9 +38,63 @@ fn test_maths() {
     println!("wrapping_geom_series {}", simplerandom::maths::wrapping_geom_series(12345_u32, 12345_u32));
 }
 
+const PROFILE_GENERATORS: [&str; 9] = [
+    "cong", "shr3", "mwc1", "mwc2", "kiss", "mwc64", "kiss2", "lfsr88", "lfsr113",
+];
+
+fn new_generator(name: &str) -> Option<Box<dyn RngCore>> {
+    let rng: Box<dyn RngCore> = match name {
+        "cong" => Box::new(simplerandom::Cong::new(1)),
+        "shr3" => Box::new(simplerandom::SHR3::new(1)),
+        "mwc1" => Box::new(simplerandom::MWC1::new(1, 2)),
+        "mwc2" => Box::new(simplerandom::MWC2::new(1, 2)),
+        "kiss" => Box::new(simplerandom::KISS::new(1, 2, 3, 4)),
+        "mwc64" => Box::new(simplerandom::MWC64::new(1, 2)),
+        "kiss2" => Box::new(simplerandom::KISS2::new(1, 2, 3, 4)),
+        "lfsr88" => Box::new(simplerandom::LFSR88::new(1, 2, 3)),
+        "lfsr113" => Box::new(simplerandom::LFSR113::new(1, 2, 3, 4)),
+        _ => return None,
+    };
+    Some(rng)
+}
+
+/// Print the per-bit statistics of a generator, or of all of them.
+///
+/// Usage: `simplerandom bit-profile [generator] [n]`
+fn bit_profile(args: &[String]) {
+    let n = match args.get(1).map_or(Ok(10_000), |s| s.parse::<usize>()) {
+        Ok(n) if n > 0 => n,
+        _ => {
+            eprintln!(
+                "usage: simplerandom bit-profile [generator] [n], where n is a positive number of outputs"
+            );
+            return;
+        }
+    };
+    let names: Vec<&str> = match args.first() {
+        Some(name) => vec![name.as_str()],
+        None => PROFILE_GENERATORS.to_vec(),
+    };
+    for name in names {
+        match new_generator(name) {
+            Some(mut rng) => println!("{}\n{}\n", name, stats::bit_profile(&mut *rng, n)),
+            None => eprintln!(
+                "unknown generator '{}'; expected one of {:?}",
+                name, PROFILE_GENERATORS
+            ),
+        }
+    }
+}
+
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(|s| s.as_str()) == Some("bit-profile") {
+        bit_profile(&args[1..]);
+        return;
+    }
+
     test_new_and_next_u32();
 
-    println!("");
+    println!();
     test_maths();
 }
\ No newline at end of file
@@ -137,6 +137,30 @@ where
     T::mul_mod(a, b, m)
 }
 
+/// Add unsigned `a` and `b`, modulo `m`
+///
+/// The calculation is done without overflow, even when `m` is close to the
+/// maximum value of the integer type.
+///
+/// # Arguments
+///
+/// `a` and `b` must already be reduced modulo `m`, that is, less than `m`.
+///
+///     use simplerandom::maths::add_mod;
+///     let result = add_mod(0xFFFFFFF0_u32, 0xFFFFFFF1, 0xFFFFFFFB);
+///     assert_eq!(result, 0xFFFFFFE6_u32);
+///
+pub fn add_mod<T>(a: T, b: T, m: T) -> T
+where
+    T: UIntTypes,
+{
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
 /// Primitive integer types
 ///
 /// Mappings to associated signed and unsigned types with the same bit width.
@@ -170,9 +194,9 @@ where
     if a < T::ZERO {
         // Negative input. Negate it.
         let result: Option<T::UnsignedType> = NumCast::from(a.wrapping_neg());
-        if result.is_some() {
+        if let Some(result) = result {
             // The vast majority of values.
-            result.unwrap()
+            result
         } else {
             // The exceptional case: in two's complement form, the lowest
             // negative number's negation doesn't fit into the signed type.
@@ -310,23 +334,32 @@ where
 /// The result is the same unsigned type as that of parameter `m`.
 /// The result is in the range [0..m] even when `a` is negative.
 ///
+/// If `m` is 0, it stands for 2^n where n is the number of bits of type `M`, so the result is `a`
+/// with the natural wrapping of type `M`. This is the same convention as the `MOD` parameter of
+/// [`Lcg`](crate::Lcg), and allows a modulus of 2^128 with `u128`.
+///
 ///     use simplerandom::maths::modulo;
 ///     let result = modulo(12345_u32, 7_u32);
 ///     assert_eq!(result, 4_u32);
 ///     let result = modulo(-12345_i32, 7_u32);
 ///     assert_eq!(result, 3_u32);
+///     let result = modulo(-1_i32, 0_u128);
+///     assert_eq!(result, u128::MAX);
 ///
 pub fn modulo<A, M>(a: A, m: M) -> M
 where
     A: IntTypes,
     M: PrimInt + Unsigned + ConstZero + Copy + NumCast,
 {
+    if m == M::ZERO {
+        return modulo_wrapping(a);
+    }
     if a >= A::ZERO {
         // Positive input.
         let a_opt: Option<M> = NumCast::from(a);
-        if a_opt.is_some() {
+        if let Some(a_m) = a_opt {
             // a fits into type M. Easy.
-            a_opt.unwrap() % m
+            a_m % m
         } else {
             // a doesn't fit into type M. m should fit into type A.
             let m_opt: Option<A> = NumCast::from(m);
@@ -338,9 +371,9 @@ where
         // Negative input.
         let a_abs = abs_as_unsigned(a);
         let a_abs_opt: Option<M> = NumCast::from(a_abs);
-        if a_abs_opt.is_some() {
+        if let Some(a_abs_m) = a_abs_opt {
             // a_abs fits into type M.
-            m - (a_abs_opt.unwrap() % m)
+            m - (a_abs_m % m)
         } else {
             // a_abs doesn't fit into type M. m should fit into the corresponding unsigned type of A.
             let m_opt: Option<A::UnsignedType> = NumCast::from(m);
@@ -352,6 +385,42 @@ where
     }
 }
 
+/// Calculate `a` modulo 2^n, where n is the number of bits of type `M`
+fn modulo_wrapping<A, M>(a: A) -> M
+where
+    A: IntTypes,
+    M: PrimInt + Unsigned + ConstZero + Copy + NumCast,
+{
+    if a >= A::ZERO {
+        let a_opt: Option<M> = NumCast::from(a);
+        if let Some(a_m) = a_opt {
+            a_m
+        } else {
+            // a doesn't fit into type M, so type A is wider. Keep the low bits.
+            let mask_opt: Option<A> = NumCast::from(M::max_value());
+            let result_m: Option<M> = NumCast::from(a & mask_opt.unwrap());
+            result_m.unwrap()
+        }
+    } else {
+        let a_abs = abs_as_unsigned(a);
+        let a_abs_opt: Option<M> = NumCast::from(a_abs);
+        let a_abs_m = if let Some(a_abs_m) = a_abs_opt {
+            a_abs_m
+        } else {
+            // a_abs doesn't fit into type M, so type A is wider. Keep the low bits.
+            let mask_opt: Option<A::UnsignedType> = NumCast::from(M::max_value());
+            let result_m: Option<M> = NumCast::from(a_abs & mask_opt.unwrap());
+            result_m.unwrap()
+        };
+        if a_abs_m == M::ZERO {
+            M::ZERO
+        } else {
+            // 2^n - a_abs_m, without overflowing type M.
+            M::max_value() - a_abs_m + M::one()
+        }
+    }
+}
+
 /// Exponentiation with wrapping
 ///
 /// Calculation of `base` to the power of an unsigned integer `n`, with the
@@ -476,3 +545,496 @@ where
     result = result.wrapping_add(&mult);
     result
 }
+
+/// Calculate geometric series, modulo `m`
+///
+/// That is, calculate the geometric series:
+///
+/// 1 + r + r^2 + r^3 + ... r^(n-1)
+///
+/// summed to `n` terms, modulo a value `m`. This is the same calculation as
+/// [`wrapping_geom_series`], but for an arbitrary modulus rather than the
+/// natural modulo of the integer type. The modulus does not need to be
+/// prime; in particular, there is no division by `r - 1`.
+///
+///     use simplerandom::maths::geom_series_mod;
+///     let result = geom_series_mod(12345_u32, 1500000_u32, 1211400191_u32);
+///     assert_eq!(result, 842139758_u32);
+///
+pub fn geom_series_mod<T, N>(r: T, n: N, m: T) -> T
+where
+    T: UIntTypes,
+    N: PrimInt + Unsigned + ConstOne + ConstZero + BitAnd,
+{
+    let mut temp_r = r % m;
+    let mut mult = T::ONE % m;
+    let mut result = T::ZERO;
+
+    if n == N::ZERO {
+        return T::ZERO;
+    }
+
+    let mut n_work = n;
+    while n_work > N::ONE {
+        if n_work & N::ONE != N::ZERO {
+            result = add_mod(
+                mul_mod(pow_mod(temp_r, n_work - N::ONE, m), mult, m),
+                result,
+                m,
+            );
+        }
+        mult = mul_mod(add_mod(T::ONE % m, temp_r, m), mult, m);
+        temp_r = mul_mod(temp_r, temp_r, m);
+        n_work = n_work >> 1;
+    }
+    result = add_mod(result, mult, m);
+    result
+}
+
+/// Greatest common divisor
+///
+/// By Euclid's algorithm. gcd(a, 0) is `a`, so gcd(0, 0) is 0.
+///
+///     use simplerandom::maths::gcd;
+///     let result = gcd(1071_u32, 462_u32);
+///     assert_eq!(result, 21_u32);
+///
+pub fn gcd<T>(a: T, b: T) -> T
+where
+    T: UIntTypes,
+{
+    let mut a = a;
+    let mut b = b;
+    while b != T::ZERO {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Extended Euclid's algorithm, with the Bézout coefficients modulo 2^bits.
+///
+/// The coefficients are calculated with wrapping arithmetic, since the intermediate values can
+/// overflow the signed type even though the final values don't.
+fn extended_gcd_wrapping<T>(a: T, b: T) -> (T, T, T)
+where
+    T: UIntTypes + WrappingMul,
+{
+    let (mut r0, mut r1) = (a, b);
+    let (mut s0, mut s1) = (T::ONE, T::ZERO);
+    let (mut t0, mut t1) = (T::ZERO, T::ONE);
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+        let r2 = r0 - q * r1;
+        let s2 = s0.wrapping_sub(&q.wrapping_mul(&s1));
+        let t2 = t0.wrapping_sub(&q.wrapping_mul(&t1));
+        r0 = r1;
+        r1 = r2;
+        s0 = s1;
+        s1 = s2;
+        t0 = t1;
+        t1 = t2;
+    }
+    (r0, s0, t0)
+}
+
+/// Extended greatest common divisor
+///
+/// The result is `(g, x, y)`, where `g` is the greatest common divisor of `a` and `b`, and the
+/// Bézout coefficients `x` and `y` satisfy `a*x + b*y = g`. The coefficients are of the signed
+/// type of the same bit width. They are the minimal ones found by the extended Euclid's
+/// algorithm, with |x| <= b/(2g) and |y| <= a/(2g) except in the trivial cases, so they always
+/// fit.
+///
+///     use simplerandom::maths::extended_gcd;
+///     let result = extended_gcd(240_u32, 46_u32);
+///     assert_eq!(result, (2_u32, -9_i32, 47_i32));
+///
+pub fn extended_gcd<T>(a: T, b: T) -> (T, T::SignedType, T::SignedType)
+where
+    T: UIntTypes + IntTypes + WrappingMul,
+{
+    let (g, x, y) = extended_gcd_wrapping(a, b);
+    (g, wrapping_as_signed(x), wrapping_as_signed(y))
+}
+
+/// Interpret an unsigned value as two's complement, in the signed type of the same bit width.
+fn wrapping_as_signed<T>(a: T) -> T::SignedType
+where
+    T: UIntTypes + IntTypes,
+{
+    if a <= T::max_value() >> 1 {
+        NumCast::from(a).unwrap()
+    } else {
+        let a_neg: T::SignedType = NumCast::from(T::ZERO.wrapping_sub(&a)).unwrap();
+        T::SignedType::ZERO - a_neg
+    }
+}
+
+/// Modular multiplicative inverse
+///
+/// Calculate `x` such that `a*x = 1` modulo `m`, in the range [0..m). The result is `None` if
+/// `a` and `m` are not coprime, since then there is no inverse, or if `m` is 0.
+///
+/// This is what's needed to step a prime-modulus LCG, or the LCG equivalent of an MWC, backwards.
+///
+///     use simplerandom::maths::mod_inverse;
+///     let result = mod_inverse(16807_u32, 2147483647_u32);
+///     assert_eq!(result, Some(1407677000_u32));
+///     let result = mod_inverse(6_u32, 9_u32);
+///     assert_eq!(result, None);
+///
+pub fn mod_inverse<T>(a: T, m: T) -> Option<T>
+where
+    T: UIntTypes + WrappingMul,
+{
+    if m == T::ZERO {
+        return None;
+    }
+    let (g, x, _) = extended_gcd_wrapping(a % m, m);
+    if g != T::ONE {
+        return None;
+    }
+    if x <= T::max_value() >> 1 {
+        // Non-negative coefficient, at most m/2.
+        Some(x % m)
+    } else {
+        // Negative coefficient, so add m.
+        Some(x.wrapping_add(&m))
+    }
+}
+
+/// Multiplicative inverse with wrapping
+///
+/// Calculate `x` such that `a*x = 1` with the natural modulo of the unsigned integer type T, that
+/// is modulo 2^bits. Only odd numbers have an inverse, so for even `a` the result is `None`.
+///
+/// This is needed to step a power-of-two modulus LCG, such as `Cong`, backwards. It is calculated
+/// by Newton's iteration, `x = x*(2 - a*x)`, which doubles the number of correct bits each time.
+/// It starts with `x = a`, which is correct to 3 bits since the square of any odd number is 1
+/// modulo 8.
+///
+///     use simplerandom::maths::wrapping_inverse;
+///     let result = wrapping_inverse(69069_u32);
+///     assert_eq!(result, Some(2783094533_u32));
+///     assert_eq!(69069_u32.wrapping_mul(2783094533), 1);
+///     let result = wrapping_inverse(65536_u32);
+///     assert_eq!(result, None);
+///
+pub fn wrapping_inverse<T>(a: T) -> Option<T>
+where
+    T: PrimInt + Unsigned + WrappingMul + WrappingSub + ConstOne,
+{
+    if a & T::ONE != T::ONE {
+        return None;
+    }
+    let two = T::ONE + T::ONE;
+    let mut x = a;
+    while a.wrapping_mul(&x) != T::ONE {
+        x = x.wrapping_mul(&two.wrapping_sub(&a.wrapping_mul(&x)));
+    }
+    Some(x)
+}
+
+/// Combine two congruences by the Chinese remainder theorem
+///
+/// Find `x` such that `x = a1` modulo `m1` and `x = a2` modulo `m2`. The moduli don't need to be
+/// coprime. The result is `Some((x, m))`, where `m` is the least common multiple of `m1` and `m2`,
+/// and `x` is unique in the range [0..m). It is `None` if the congruences are inconsistent, if
+/// `m1` or `m2` is 0, or if `m` overflows T.
+///
+/// This is useful for combined generators. If the distance from one state to another is known
+/// for each component, modulo that component's period, then the distance for the combined
+/// generator is found by combining them.
+///
+///     use simplerandom::maths::crt_combine;
+///     let result = crt_combine(2_u32, 3_u32, 3_u32, 5_u32);
+///     assert_eq!(result, Some((8_u32, 15_u32)));
+///     let result = crt_combine(3_u32, 4_u32, 5_u32, 6_u32);
+///     assert_eq!(result, Some((11_u32, 12_u32)));
+///     let result = crt_combine(2_u32, 4_u32, 3_u32, 6_u32);
+///     assert_eq!(result, None);
+///
+pub fn crt_combine<T>(a1: T, m1: T, a2: T, m2: T) -> Option<(T, T)>
+where
+    T: UIntTypes + WrappingMul,
+{
+    if m1 == T::ZERO || m2 == T::ZERO {
+        return None;
+    }
+    let a1 = a1 % m1;
+    let a2 = a2 % m2;
+    let g = gcd(m1, m2);
+    let m = (m1 / g).checked_mul(&m2)?;
+
+    // x = a1 + m1*k, where m1*k = a2 - a1 modulo m2.
+    let a1_mod_m2 = a1 % m2;
+    let diff = if a2 >= a1_mod_m2 {
+        a2 - a1_mod_m2
+    } else {
+        m2 - (a1_mod_m2 - a2)
+    };
+    if diff % g != T::ZERO {
+        return None;
+    }
+    let m2_g = m2 / g;
+    let m1_g_inverse = mod_inverse(m1 / g, m2_g)?;
+    let k = mul_mod(diff / g, m1_g_inverse, m2_g);
+    Some((a1 + m1 * k, m))
+}
+
+/// Prime bases for the Miller–Rabin test, which are also used for trial division.
+///
+/// Testing with all of these bases is a deterministic primality test for
+/// n < 3317044064679887385961981.
+const MILLER_RABIN_BASES: [u8; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// Primality test
+///
+/// This is the Miller–Rabin test with the first 13 prime bases, which is deterministic for all
+/// `n` less than 3317044064679887385961981 (about 2^81). That covers all u8 to u64 values. For
+/// larger u128 values, a `true` result means that `n` is a strong probable prime to all those
+/// bases; no composite number is known to pass, but that isn't proven.
+///
+///     use simplerandom::maths::is_prime;
+///     assert!(is_prime(2147483647_u32));
+///     assert!(!is_prime(4294967297_u64));
+///     assert!(is_prime(18446742282708232657_u128));
+///
+pub fn is_prime<T>(n: T) -> bool
+where
+    T: UIntTypes,
+{
+    let two = T::ONE + T::ONE;
+    if n < two {
+        return false;
+    }
+    for &p in MILLER_RABIN_BASES.iter() {
+        let p: T = NumCast::from(p).unwrap();
+        if n == p {
+            return true;
+        }
+        if n % p == T::ZERO {
+            return false;
+        }
+    }
+
+    // n - 1 = d 2^s, with d odd.
+    let n_minus_1 = n - T::ONE;
+    let s = n_minus_1.trailing_zeros();
+    let d = n_minus_1 >> s as usize;
+    'bases: for &a in MILLER_RABIN_BASES.iter() {
+        let a: T = NumCast::from(a).unwrap();
+        let mut x = pow_mod(a, d, n);
+        if x == T::ONE || x == n_minus_1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n_minus_1 {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Find a non-trivial factor of a composite number, by Pollard's rho algorithm
+///
+/// It iterates x^2 + c modulo `n`, with Floyd's cycle detection, until a cycle modulo a factor of
+/// `n` is found. It takes time roughly proportional to the square root of the smallest prime
+/// factor. `n` must be composite; if it is prime, or 1, this will never return.
+///
+///     use simplerandom::maths::pollard_rho;
+///     let factor = pollard_rho(10403_u32);
+///     assert!(factor == 101 || factor == 103);
+///
+pub fn pollard_rho<T>(n: T) -> T
+where
+    T: UIntTypes,
+{
+    let two = T::ONE + T::ONE;
+    if n % two == T::ZERO {
+        return two;
+    }
+    let mut c = T::ONE;
+    loop {
+        let f = |x: T| add_mod(mul_mod(x, x, n), c, n);
+        let mut x = two;
+        let mut y = two;
+        let mut d = T::ONE;
+        while d == T::ONE {
+            x = f(x);
+            y = f(f(y));
+            let diff = if x > y { x - y } else { y - x };
+            d = gcd(diff, n);
+        }
+        if d != n {
+            return d;
+        }
+        // The cycles modulo all the factors coincided. Try another polynomial.
+        c = c + T::ONE;
+    }
+}
+
+/// Prime factorisation
+///
+/// The result is a list of the distinct prime factors, with their exponents, in increasing order
+/// of the prime factors. Small factors are found by trial division, and others by Pollard's rho
+/// algorithm. For 1 and 0, the result is empty.
+///
+///     use simplerandom::maths::factorise;
+///     let factors = factorise(0xFFFFFFFF_u32);
+///     assert_eq!(factors, vec![(3, 1), (5, 1), (17, 1), (257, 1), (65537, 1)]);
+///     let factors = factorise(1_u64 << 40);
+///     assert_eq!(factors, vec![(2, 40)]);
+///
+pub fn factorise<T>(n: T) -> Vec<(T, u32)>
+where
+    T: UIntTypes,
+{
+    let mut primes: Vec<T> = Vec::new();
+    if n == T::ZERO {
+        return Vec::new();
+    }
+    let mut rest = n;
+    for &p in MILLER_RABIN_BASES.iter() {
+        let p: T = NumCast::from(p).unwrap();
+        while rest % p == T::ZERO {
+            primes.push(p);
+            rest = rest / p;
+        }
+    }
+    let mut composites = Vec::new();
+    if rest != T::ONE {
+        composites.push(rest);
+    }
+    while let Some(m) = composites.pop() {
+        if is_prime(m) {
+            primes.push(m);
+        } else {
+            let factor = pollard_rho(m);
+            composites.push(factor);
+            composites.push(m / factor);
+        }
+    }
+    primes.sort();
+
+    let mut result: Vec<(T, u32)> = Vec::new();
+    for p in primes {
+        match result.last_mut() {
+            Some((last, exponent)) if *last == p => *exponent += 1,
+            _ => result.push((p, 1)),
+        }
+    }
+    result
+}
+
+/// Euler's totient function φ(n), from the prime factorisation of n, along with the prime
+/// factorisation of φ(n).
+fn totient_factorised<T>(n: T) -> (T, Vec<(T, u32)>)
+where
+    T: UIntTypes,
+{
+    let mut totient = T::ONE;
+    let mut totient_primes: Vec<T> = Vec::new();
+    for (p, exponent) in factorise(n) {
+        for _ in 1..exponent {
+            totient = totient * p;
+            totient_primes.push(p);
+        }
+        totient = totient * (p - T::ONE);
+        for (q, q_exponent) in factorise(p - T::ONE) {
+            for _ in 0..q_exponent {
+                totient_primes.push(q);
+            }
+        }
+    }
+    totient_primes.sort();
+    totient_primes.dedup();
+    let totient_factors = totient_primes
+        .into_iter()
+        .map(|q| {
+            let mut exponent = 0;
+            let mut t = totient;
+            while t % q == T::ZERO {
+                t = t / q;
+                exponent += 1;
+            }
+            (q, exponent)
+        })
+        .collect();
+    (totient, totient_factors)
+}
+
+/// Multiplicative order of `a` modulo `m`
+///
+/// That is, the smallest k > 0 such that a^k = 1 modulo `m`. It is found from the prime
+/// factorisation of φ(m), which the order must divide. The result is `None` if `a` and `m` are
+/// not coprime, since then there is no such k.
+///
+///     use simplerandom::maths::multiplicative_order;
+///     // MWC8: the order of 2^8 modulo 249 * 2^8 - 1.
+///     assert_eq!(multiplicative_order(256_u32, 63743), Some(31871));
+///     assert_eq!(multiplicative_order(6_u32, 9), None);
+///
+pub fn multiplicative_order<T>(a: T, m: T) -> Option<T>
+where
+    T: UIntTypes,
+{
+    if m == T::ZERO {
+        return None;
+    }
+    if m == T::ONE {
+        return Some(T::ONE);
+    }
+    if gcd(a % m, m) != T::ONE {
+        return None;
+    }
+    let (totient, factors) = totient_factorised(m);
+    Some(multiplicative_order_of_totient(a, m, totient, &factors))
+}
+
+/// Multiplicative order of `a` modulo `m`, given φ(m) and its prime factorisation. `a` and `m`
+/// must be coprime.
+fn multiplicative_order_of_totient<T>(a: T, m: T, totient: T, factors: &[(T, u32)]) -> T
+where
+    T: UIntTypes,
+{
+    let mut order = totient;
+    for &(q, _) in factors {
+        while order % q == T::ZERO && pow_mod(a % m, order / q, m) == T::ONE {
+            order = order / q;
+        }
+    }
+    order
+}
+
+/// Check whether `g` is a primitive root modulo `m`
+///
+/// That is, whether the multiplicative order of `g` modulo `m` is φ(m), so that its powers
+/// generate all the numbers coprime to `m`.
+///
+///     use simplerandom::maths::is_primitive_root;
+///     // MINSTD's multiplier.
+///     assert!(is_primitive_root(16807_u32, 2147483647));
+///     assert!(!is_primitive_root(2_u32, 2147483647));
+///
+pub fn is_primitive_root<T>(g: T, m: T) -> bool
+where
+    T: UIntTypes,
+{
+    if m == T::ZERO {
+        return false;
+    }
+    if m == T::ONE {
+        return true;
+    }
+    if gcd(g % m, m) != T::ONE {
+        return false;
+    }
+    let (totient, factors) = totient_factorised(m);
+    multiplicative_order_of_totient(g, m, totient, &factors) == totient
+}
@@ -0,0 +1,143 @@
+//! Square matrix of integers, modulo m
+//!
+//! The matrix elements are unsigned integers in the range [0..m). Matrix
+//! multiplication is done modulo m, using [`crate::maths::mul_mod`], so the
+//! modulus can be close to the maximum value of the integer type.
+//!
+//! This is used for jumpahead of multiple recursive generators (MRG), in the
+//! same way that `BitColumnMatrix` is used for the LFSR generators.
+
+use crate::maths::{self, UIntTypes};
+use num_traits::{ConstOne, ConstZero, Pow, PrimInt, Unsigned};
+use std::ops::BitAnd;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModMatrix<T, const WIDTH: usize>
+where
+    T: UIntTypes,
+{
+    rows: [[T; WIDTH]; WIDTH],
+    m: T,
+}
+
+impl<T, const WIDTH: usize> ModMatrix<T, WIDTH>
+where
+    T: UIntTypes,
+{
+    /// Create a matrix from an array of rows, with modulus `m`.
+    ///
+    /// All the elements must be less than `m`.
+    pub fn new(init_data: &[[T; WIDTH]; WIDTH], m: T) -> ModMatrix<T, WIDTH> {
+        ModMatrix::<T, WIDTH> {
+            rows: *init_data,
+            m,
+        }
+    }
+
+    /// Create a zero-matrix, with modulus `m`.
+    pub fn zero(m: T) -> ModMatrix<T, WIDTH> {
+        ModMatrix::<T, WIDTH> {
+            rows: [[T::ZERO; WIDTH]; WIDTH],
+            m,
+        }
+    }
+
+    /// Create a unity-matrix, with modulus `m`. That is, ones on the diagonal, zeros elsewhere.
+    pub fn one(m: T) -> ModMatrix<T, WIDTH> {
+        let mut result = ModMatrix::<T, WIDTH>::zero(m);
+        for i in 0..WIDTH {
+            result.rows[i][i] = T::ONE;
+        }
+        result
+    }
+
+    pub fn modulus(&self) -> T {
+        self.m
+    }
+
+    pub fn rows(&self) -> &[[T; WIDTH]; WIDTH] {
+        &self.rows
+    }
+
+    pub fn dot_vec(&self, b: &[T; WIDTH]) -> [T; WIDTH] {
+        let mut result = [T::ZERO; WIDTH];
+        for (result_i, row) in result.iter_mut().zip(self.rows.iter()) {
+            for (row_j, b_j) in row.iter().zip(b.iter()) {
+                *result_i = maths::add_mod(*result_i, maths::mul_mod(*row_j, *b_j, self.m), self.m);
+            }
+        }
+        result
+    }
+
+    pub fn dot(&self, b: &ModMatrix<T, WIDTH>) -> ModMatrix<T, WIDTH> {
+        let mut result = ModMatrix::<T, WIDTH>::zero(self.m);
+        for i in 0..WIDTH {
+            for j in 0..WIDTH {
+                for k in 0..WIDTH {
+                    result.rows[i][j] = maths::add_mod(
+                        result.rows[i][j],
+                        maths::mul_mod(self.rows[i][k], b.rows[k][j], self.m),
+                        self.m,
+                    );
+                }
+            }
+        }
+        result
+    }
+
+    pub fn dot_equ(&mut self, b: &ModMatrix<T, WIDTH>) {
+        *self = self.dot(b);
+    }
+}
+
+impl<N, T, const WIDTH: usize> Pow<N> for ModMatrix<T, WIDTH>
+where
+    T: UIntTypes,
+    N: Unsigned + PrimInt + BitAnd + ConstOne + ConstZero,
+{
+    type Output = Self;
+
+    /// Raise a matrix to a power. Efficient matrix exponentiation.
+    fn pow(self, n: N) -> ModMatrix<T, WIDTH> {
+        let mut result = ModMatrix::<T, WIDTH>::one(self.m);
+        let mut temp_exp = self;
+        let mut n_work: N = n;
+
+        loop {
+            if n_work & N::ONE != N::ZERO {
+                result.dot_equ(&temp_exp);
+            }
+            n_work = n_work >> 1;
+            if n_work == N::ZERO {
+                break;
+            }
+            let temp_exp2 = temp_exp.clone();
+            temp_exp.dot_equ(&temp_exp2);
+        }
+        result
+    }
+}
+
+impl<T, const WIDTH: usize> core::ops::Mul for ModMatrix<T, WIDTH>
+where
+    T: UIntTypes,
+{
+    type Output = Self;
+
+    /// Multiply two matrices.
+    fn mul(self, b: ModMatrix<T, WIDTH>) -> ModMatrix<T, WIDTH> {
+        self.dot(&b)
+    }
+}
+
+impl<'b, T, const WIDTH: usize> core::ops::Mul<&'b ModMatrix<T, WIDTH>> for &ModMatrix<T, WIDTH>
+where
+    T: UIntTypes,
+{
+    type Output = ModMatrix<T, WIDTH>;
+
+    /// Multiply two matrices (by reference).
+    fn mul(self, b: &'b ModMatrix<T, WIDTH>) -> ModMatrix<T, WIDTH> {
+        self.dot(b)
+    }
+}
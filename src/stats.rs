@@ -0,0 +1,723 @@
+//! Statistical tests of random number generators
+//!
+//! A small battery of empirical tests, in the style of TestU01's SmallCrush
+//! [[lecuyer5]](crate#lecuyer5), that works on any `RngCore`. It is a quick smoke test of a
+//! generator, not a substitute for the full external test suites.
+//!
+//! Each test returns a p-value: the probability that a truly random sequence would give a result
+//! at least as extreme. A p-value very close to 0 (less than [`ALPHA`]) is a failure. So is a NaN
+//! p-value, as given by a test run on no outputs. Since the tests are run on a seeded generator,
+//! the results are deterministic for a given seed.
+//!
+//! Most tests use the most significant bits of each 32-bit output, so weaknesses in the low bits
+//! are only seen when the bits are reversed, by testing [`Reversed`]. Eg the low bits of `Cong`
+//! have short periods, which the serial pairs test finds in the reversed output. `SHR3` fails the
+//! matrix rank and linear complexity tests, because it's linear over Galois(2)
+//! [[mars1]](crate#mars1). The combined LFSR generators also fail the linear complexity
+//! test, since their most significant bit satisfies a linear recurrence of degree 88 or 113.
+//!
+//! [`bit_profile`] instead examines each bit position of the outputs separately, to show which
+//! bits of a generator can be trusted.
+
+use crate::bitcolumnmatrix::BitColumnMatrix;
+use crate::gf2poly::Gf2Poly;
+use rand_core::{impls, Error, RngCore};
+use std::fmt;
+
+/// Significance level below which a p-value is counted as a failure.
+pub const ALPHA: f64 = 0.001;
+
+/// Result of one statistical test
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestResult {
+    pub name: &'static str,
+    pub p_value: f64,
+}
+
+impl TestResult {
+    /// Whether the p-value is at least [`ALPHA`]. A NaN p-value doesn't pass.
+    pub fn passed(&self) -> bool {
+        self.p_value >= ALPHA
+    }
+}
+
+/// Summary of the results of a battery of tests
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    results: Vec<TestResult>,
+}
+
+impl Report {
+    pub fn new() -> Report {
+        Report::default()
+    }
+
+    /// Add a test result.
+    pub fn push(&mut self, name: &'static str, p_value: f64) {
+        self.results.push(TestResult { name, p_value });
+    }
+
+    pub fn results(&self) -> &[TestResult] {
+        &self.results
+    }
+
+    /// p-value of the named test, if it was run.
+    pub fn p_value(&self, name: &str) -> Option<f64> {
+        self.results
+            .iter()
+            .find(|r| r.name == name)
+            .map(|r| r.p_value)
+    }
+
+    /// Tests that didn't pass, with a p-value less than [`ALPHA`] or NaN.
+    pub fn failures(&self) -> Vec<&TestResult> {
+        self.results.iter().filter(|r| !r.passed()).collect()
+    }
+
+    /// Whether all tests passed.
+    pub fn passed(&self) -> bool {
+        self.failures().is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for r in self.results.iter() {
+            let verdict = if r.passed() { "pass" } else { "FAIL" };
+            writeln!(f, "{:<20} {:<12.4e} {}", r.name, r.p_value, verdict)?;
+        }
+        let failures = self.failures().len();
+        write!(
+            f,
+            "{} of {} tests passed",
+            self.results.len() - failures,
+            self.results.len()
+        )
+    }
+}
+
+/// Generator adapter that reverses the order of the bits of each 32-bit output
+///
+/// The tests mostly look at the most significant bits of the outputs. Testing the reversed
+/// generator puts the least significant bits under scrutiny instead.
+#[derive(Debug, Clone)]
+pub struct Reversed<R>(pub R);
+
+impl<R> RngCore for Reversed<R>
+where
+    R: RngCore,
+{
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32().reverse_bits()
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Run the whole battery of tests on a generator.
+///
+/// It uses about 3 million outputs of the generator.
+///
+///     use simplerandom::stats;
+///     let mut rng = simplerandom::KISS::new(1, 2, 3, 4);
+///     let report = stats::small_battery(&mut rng);
+///     assert!(report.passed());
+pub fn small_battery<R>(rng: &mut R) -> Report
+where
+    R: RngCore + ?Sized,
+{
+    let mut report = Report::new();
+    report.push("monobit", monobit(rng, 100_000));
+    report.push("runs", runs(rng, 100_000));
+    report.push("serial pairs", serial_pairs(rng, 200_000));
+    report.push("birthday spacings", birthday_spacings(rng, 100));
+    report.push("collision", collision(rng, 1 << 14));
+    report.push("gap", gap(rng, 10_000));
+    report.push("poker", poker(rng, 100_000));
+    report.push("max-of-t", max_of_t(rng, 100_000));
+    report.push("matrix rank", matrix_rank(rng, 1_000));
+    report.push("linear complexity", linear_complexity(rng, 1_000));
+    report
+}
+
+/* Tests --------------------------------------------------------------------*/
+
+/// Frequency (monobit) test on all the bits of `n` outputs.
+///
+/// The number of ones should be close to half the number of bits.
+pub fn monobit<R>(rng: &mut R, n: usize) -> f64
+where
+    R: RngCore + ?Sized,
+{
+    let bits = 32 * n as u64;
+    let ones: u64 = (0..n).map(|_| rng.next_u32().count_ones() as u64).sum();
+    let s = (2.0 * ones as f64 - bits as f64) / (bits as f64).sqrt();
+    normal_p_value(s)
+}
+
+/// Runs test on the bit stream of `n` outputs, most significant bit first.
+///
+/// The number of runs of identical bits should match that of independent bits with the observed
+/// frequency of ones.
+pub fn runs<R>(rng: &mut R, n: usize) -> f64
+where
+    R: RngCore + ?Sized,
+{
+    let bits = 32 * n as u64;
+    let mut ones = 0_u64;
+    let mut transitions = 0_u64;
+    let mut previous_bit = None;
+    for _ in 0..n {
+        let x = rng.next_u32();
+        ones += x.count_ones() as u64;
+        // Transitions within the word, and from the previous word's last bit.
+        transitions += ((x ^ (x >> 1)) & 0x7FFF_FFFF).count_ones() as u64;
+        if let Some(bit) = previous_bit {
+            transitions += (bit ^ (x >> 31)) as u64;
+        }
+        previous_bit = Some(x & 1);
+    }
+    let pi = ones as f64 / bits as f64;
+    if (pi - 0.5).abs() >= 2.0 / (bits as f64).sqrt() {
+        // The frequency is so far off that the runs test isn't meaningful.
+        return 0.0;
+    }
+    let runs = (transitions + 1) as f64;
+    let expected = 2.0 * bits as f64 * pi * (1.0 - pi);
+    let s = (runs - expected) / (2.0 * (2.0 * bits as f64).sqrt() * pi * (1.0 - pi));
+    normal_p_value(s * std::f64::consts::SQRT_2)
+}
+
+/// Serial test on `n` non-overlapping pairs of outputs, using the 6 most significant bits of
+/// each.
+///
+/// All 4096 combinations should be equally likely.
+pub fn serial_pairs<R>(rng: &mut R, n: usize) -> f64
+where
+    R: RngCore + ?Sized,
+{
+    const BITS: u32 = 6;
+    let mut counts = vec![0_u64; 1 << (2 * BITS)];
+    for _ in 0..n {
+        let a = rng.next_u32() >> (32 - BITS);
+        let b = rng.next_u32() >> (32 - BITS);
+        counts[((a << BITS) | b) as usize] += 1;
+    }
+    let p = 1.0 / counts.len() as f64;
+    chi_square_test(&counts, &vec![p; counts.len()])
+}
+
+/// Birthday spacings test, with `samples` samples of 512 birthdays in a year of 2^24 days.
+///
+/// The days are the 24 most significant bits of each output. In each sample, the birthdays are
+/// sorted, and the number of repeated values among the spacings between them is counted. The
+/// total count is approximately Poisson distributed, with mean 2 per sample.
+pub fn birthday_spacings<R>(rng: &mut R, samples: usize) -> f64
+where
+    R: RngCore + ?Sized,
+{
+    const BIRTHDAYS: usize = 512;
+    const DAY_BITS: u32 = 24;
+    let mut repeats = 0_u64;
+    for _ in 0..samples {
+        let mut days: Vec<u32> = (0..BIRTHDAYS)
+            .map(|_| rng.next_u32() >> (32 - DAY_BITS))
+            .collect();
+        days.sort_unstable();
+        let mut spacings: Vec<u32> = days.windows(2).map(|w| w[1] - w[0]).collect();
+        spacings.sort_unstable();
+        repeats += spacings.windows(2).filter(|w| w[0] == w[1]).count() as u64;
+    }
+    let lambda = samples as f64 * (BIRTHDAYS as f64).powi(3) / (4.0 * (1_u64 << DAY_BITS) as f64);
+    poisson_p_value(repeats, lambda)
+}
+
+/// Collision test, throwing `n` balls into 2^20 cells.
+///
+/// Each cell is given by the 10 most significant bits of two successive outputs. The number of
+/// balls landing in an already-occupied cell is approximately Poisson distributed, when `n` is
+/// much smaller than the number of cells.
+pub fn collision<R>(rng: &mut R, n: usize) -> f64
+where
+    R: RngCore + ?Sized,
+{
+    const BITS: u32 = 10;
+    let cells = 1_usize << (2 * BITS);
+    let mut occupied = vec![false; cells];
+    let mut collisions = 0_u64;
+    for _ in 0..n {
+        let a = rng.next_u32() >> (32 - BITS);
+        let b = rng.next_u32() >> (32 - BITS);
+        let cell = &mut occupied[((a << BITS) | b) as usize];
+        if *cell {
+            collisions += 1;
+        }
+        *cell = true;
+    }
+    let k = cells as f64;
+    let expected = n as f64 - k * (1.0 - (1.0 - 1.0 / k).powi(n as i32));
+    poisson_p_value(collisions, expected)
+}
+
+/// Gap test, on `n` gaps between outputs in the interval [0, 1/16).
+///
+/// The gap lengths should be geometrically distributed. Lengths of 64 or more are counted
+/// together. A gap longer than 65536, which a random sequence would practically never produce,
+/// stops the test with p-value 0, so that a generator that never outputs a value in the interval
+/// fails rather than hangs.
+pub fn gap<R>(rng: &mut R, n: usize) -> f64
+where
+    R: RngCore + ?Sized,
+{
+    const MAX_GAP: usize = 64;
+    const GAP_LIMIT: usize = MAX_GAP * 1024;
+    let p = 1.0_f64 / 16.0;
+    let mut counts = vec![0_u64; MAX_GAP + 1];
+    for _ in 0..n {
+        let mut length = 0;
+        while rng.next_u32() >> 28 != 0 {
+            length += 1;
+            if length > GAP_LIMIT {
+                return 0.0;
+            }
+        }
+        counts[length.min(MAX_GAP)] += 1;
+    }
+    let mut probabilities: Vec<f64> = (0..MAX_GAP).map(|r| p * (1.0 - p).powi(r as i32)).collect();
+    probabilities.push((1.0 - p).powi(MAX_GAP as i32));
+    chi_square_test(&counts, &probabilities)
+}
+
+/// Poker test, on `n` hands of 5 cards, using the 4 most significant bits of each output.
+///
+/// The number of distinct values in each hand should follow the distribution given by Knuth.
+/// Hands with 1 or 2 distinct values are counted together.
+pub fn poker<R>(rng: &mut R, n: usize) -> f64
+where
+    R: RngCore + ?Sized,
+{
+    const CARDS: usize = 5;
+    const BITS: u32 = 4;
+    let d = 1_u64 << BITS;
+    let mut counts = vec![0_u64; 4];
+    for _ in 0..n {
+        let mut seen = 0_u32;
+        for _ in 0..CARDS {
+            seen |= 1 << (rng.next_u32() >> (32 - BITS));
+        }
+        counts[(seen.count_ones() as usize).max(2) - 2] += 1;
+    }
+    // Stirling numbers of the second kind S(5, r), times d(d-1)...(d-r+1), over d^5.
+    const STIRLING: [u64; CARDS + 1] = [0, 1, 15, 25, 10, 1];
+    let probability = |r: usize| {
+        let falling: u64 = (0..r as u64).map(|i| d - i).product();
+        (STIRLING[r] * falling) as f64 / (d as f64).powi(CARDS as i32)
+    };
+    let probabilities = [
+        probability(1) + probability(2),
+        probability(3),
+        probability(4),
+        probability(5),
+    ];
+    chi_square_test(&counts, &probabilities)
+}
+
+/// Maximum-of-t test, on `n` groups of 8 outputs.
+///
+/// For uniform values in [0, 1), the maximum V of t values has V^t uniformly distributed, which is
+/// checked with 100 equal intervals.
+pub fn max_of_t<R>(rng: &mut R, n: usize) -> f64
+where
+    R: RngCore + ?Sized,
+{
+    const T: usize = 8;
+    const INTERVALS: usize = 100;
+    let mut counts = vec![0_u64; INTERVALS];
+    for _ in 0..n {
+        let max = (0..T).map(|_| rng.next_u32()).max().unwrap_or(0);
+        let v = (max as f64 + 0.5) / 4_294_967_296.0;
+        counts[((v.powi(T as i32) * INTERVALS as f64) as usize).min(INTERVALS - 1)] += 1;
+    }
+    chi_square_test(&counts, &[1.0 / INTERVALS as f64; INTERVALS])
+}
+
+/// Binary matrix rank test, on `n` 32×32 matrices, each made of 32 successive outputs.
+///
+/// The ranks of the matrices over Galois(2) should follow the distribution for random matrices.
+/// Ranks of 29 or less are counted together.
+pub fn matrix_rank<R>(rng: &mut R, n: usize) -> f64
+where
+    R: RngCore + ?Sized,
+{
+    const M: usize = 32;
+    let mut counts = vec![0_u64; 4];
+    for _ in 0..n {
+        let mut columns = [0_u32; M];
+        for column in columns.iter_mut() {
+            *column = rng.next_u32();
+        }
+        let rank = BitColumnMatrix::<u32, M>::new(&columns).rank();
+        counts[M - rank.max(M - 3)] += 1;
+    }
+    // Probability of rank r for a random m×m matrix:
+    // 2^(r(2m-r)-m^2) product(i=0..r-1) (1-2^(i-m))^2 / (1-2^(i-r))
+    let probability = |r: usize| {
+        let exponent = (r * (2 * M - r)) as i32 - (M * M) as i32;
+        (0..r).fold(2.0_f64.powi(exponent), |acc, i| {
+            let a = 1.0 - 2.0_f64.powi(i as i32 - M as i32);
+            acc * a * a / (1.0 - 2.0_f64.powi(i as i32 - r as i32))
+        })
+    };
+    let full = probability(M);
+    let one_less = probability(M - 1);
+    let two_less = probability(M - 2);
+    chi_square_test(
+        &counts,
+        &[full, one_less, two_less, 1.0 - full - one_less - two_less],
+    )
+}
+
+/// Linear complexity test, on `n` blocks of 500 bits from the most significant bit of successive
+/// outputs.
+///
+/// The linear complexity of each block is found by the Berlekamp–Massey algorithm, and the
+/// deviations from the expected value of about 250 should follow the distribution given in
+/// [[nist1]](crate#nist1).
+pub fn linear_complexity<R>(rng: &mut R, n: usize) -> f64
+where
+    R: RngCore + ?Sized,
+{
+    const M: usize = 500;
+    let mut counts = vec![0_u64; 7];
+    let mut bits = vec![false; M];
+    for _ in 0..n {
+        for bit in bits.iter_mut() {
+            *bit = rng.next_u32() >> 31 != 0;
+        }
+        let complexity = Gf2Poly::minimal_polynomial(&bits).degree().unwrap_or(0) as i64;
+        // For even M, the deviation T = L - M/2 is an integer; count T <= -3, -2, ..., >= 3.
+        let deviation = complexity - (M / 2) as i64;
+        counts[(deviation.clamp(-3, 3) + 3) as usize] += 1;
+    }
+    let probabilities = [
+        1.0 / 96.0,
+        1.0 / 32.0,
+        1.0 / 8.0,
+        1.0 / 2.0,
+        1.0 / 4.0,
+        1.0 / 16.0,
+        1.0 / 48.0,
+    ];
+    chi_square_test(&counts, &probabilities)
+}
+
+/* Bit profile --------------------------------------------------------------*/
+
+/// Statistics of one bit position of a generator's outputs
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitStats {
+    /// Bit position, 0 being the least significant.
+    pub bit: u32,
+    /// Fraction of ones, minus 1/2.
+    pub bias: f64,
+    pub bias_p_value: f64,
+    /// Correlation of the bit with the same bit of the next output, from -1 to 1.
+    pub correlation: f64,
+    pub correlation_p_value: f64,
+    /// Linear complexity of the bit sequence, which is about half its length if it's random.
+    pub linear_complexity: usize,
+    pub linear_complexity_p_value: f64,
+}
+
+impl BitStats {
+    /// Whether the bit passes all the tests.
+    pub fn trustworthy(&self) -> bool {
+        self.bias_p_value >= ALPHA
+            && self.correlation_p_value >= ALPHA
+            && self.linear_complexity_p_value >= ALPHA
+    }
+}
+
+/// Statistics of each bit position of a generator's outputs
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitProfile {
+    /// Number of outputs.
+    n: usize,
+    /// Statistics of each bit, least significant first.
+    bits: Vec<BitStats>,
+}
+
+impl BitProfile {
+    /// Number of outputs that were examined.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Statistics of each bit, indexed by bit position.
+    pub fn bits(&self) -> &[BitStats] {
+        &self.bits
+    }
+
+    /// Mask of the bit positions that pass all the tests.
+    pub fn trustworthy_mask(&self) -> u32 {
+        self.bits
+            .iter()
+            .filter(|b| b.trustworthy())
+            .fold(0, |mask, b| mask | (1 << b.bit))
+    }
+}
+
+impl fmt::Display for BitProfile {
+    /// Table of the statistics, most significant bit first.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "bit       bias    p-value  serial corr    p-value  lin. compl.    p-value"
+        )?;
+        for b in self.bits.iter().rev() {
+            writeln!(
+                f,
+                "{:>3} {:>+10.6} {:>10.3e} {:>+12.6} {:>10.3e} {:>12} {:>10.3e}  {}",
+                b.bit,
+                b.bias,
+                b.bias_p_value,
+                b.correlation,
+                b.correlation_p_value,
+                b.linear_complexity,
+                b.linear_complexity_p_value,
+                if b.trustworthy() { "ok" } else { "WEAK" }
+            )?;
+        }
+        write!(
+            f,
+            "{} outputs; trustworthy bits mask {:#010X}",
+            self.n,
+            self.trustworthy_mask()
+        )
+    }
+}
+
+/// Measure the statistics of each of the 32 bit positions of `n` outputs separately.
+///
+/// Each bit position is treated as a sequence of `n` bits, and checked for frequency bias, for
+/// correlation between successive bits, and for its linear complexity, found by the
+/// Berlekamp–Massey algorithm. This shows which bits of a generator are trustworthy, such as the
+/// leading bits of `Cong`, but not its trailing bits. For generators that are linear over
+/// Galois(2), such as `SHR3` and the LFSR generators, every bit has a linear complexity no more
+/// than the size of the state, so every bit is flagged once `n` is more than twice that.
+///
+/// The table can also be printed with the command-line program:
+/// `cargo run -- bit-profile [generator] [n]`.
+///
+///     use simplerandom::stats;
+///     let mut rng = simplerandom::Cong::new(1);
+///     let profile = stats::bit_profile(&mut rng, 10_000);
+///     // Bit i of Cong has period 2^(i+1).
+///     assert_eq!(profile.bits()[0].linear_complexity, 2);
+///     assert_eq!(profile.trustworthy_mask() & 0xFF, 0);
+///     assert_eq!(profile.trustworthy_mask() >> 16, 0xFFFF);
+pub fn bit_profile<R>(rng: &mut R, n: usize) -> BitProfile
+where
+    R: RngCore + ?Sized,
+{
+    const BITS: usize = 32;
+    let mut sequences: Vec<Vec<bool>> = (0..BITS).map(|_| Vec::with_capacity(n)).collect();
+    for _ in 0..n {
+        let x = rng.next_u32();
+        for (i, sequence) in sequences.iter_mut().enumerate() {
+            sequence.push((x >> i) & 1 != 0);
+        }
+    }
+
+    let bits = sequences
+        .iter()
+        .enumerate()
+        .map(|(i, sequence)| {
+            let ones = sequence.iter().filter(|&&bit| bit).count();
+            let bias_z = (2.0 * ones as f64 - n as f64) / (n as f64).sqrt();
+
+            // Sum of the products of successive bits, as values ±1.
+            let pairs = n.saturating_sub(1);
+            let agreements = sequence.windows(2).filter(|w| w[0] == w[1]).count();
+            let correlation_sum = 2.0 * agreements as f64 - pairs as f64;
+
+            // Use an even length, for which the deviation from the expected value is an integer.
+            let m = n & !1;
+            let complexity = Gf2Poly::minimal_polynomial(&sequence[..m])
+                .degree()
+                .unwrap_or(0);
+
+            BitStats {
+                bit: i as u32,
+                bias: ones as f64 / n as f64 - 0.5,
+                bias_p_value: normal_p_value(bias_z),
+                correlation: correlation_sum / pairs as f64,
+                correlation_p_value: normal_p_value(correlation_sum / (pairs as f64).sqrt()),
+                linear_complexity: complexity,
+                linear_complexity_p_value: linear_complexity_p_value(complexity, m),
+            }
+        })
+        .collect();
+    BitProfile { n, bits }
+}
+
+/// Two-sided p-value of the linear complexity `l` of a sequence of even length `m`.
+///
+/// For a random sequence, the deviation T = L - m/2 has P(T = -j) = 2^(-2j-1) and
+/// P(T = j) = 2^(-2j), for j > 0 (approximately, for large `m`). So P(T <= -k) = 2^(1-2k)/3 and
+/// P(T >= k) = 2^(2-2k)/3.
+fn linear_complexity_p_value(l: usize, m: usize) -> f64 {
+    let deviation = l as i64 - (m / 2) as i64;
+    let at_most = (2.0_f64.powi((1 + 2 * deviation.min(0)) as i32) / 3.0).min(1.0);
+    let at_least = (2.0_f64.powi((2 - 2 * deviation.max(1)) as i32) / 3.0).min(1.0);
+    let at_least = if deviation <= 0 { 1.0 } else { at_least };
+    (2.0 * at_most.min(at_least)).min(1.0)
+}
+
+/* Distributions ------------------------------------------------------------*/
+
+/// Chi-square test of observed counts against their expected probabilities, which must sum to
+/// 1. The result is the p-value.
+fn chi_square_test(counts: &[u64], probabilities: &[f64]) -> f64 {
+    let n: u64 = counts.iter().sum();
+    let chi_square: f64 = counts
+        .iter()
+        .zip(probabilities.iter())
+        .map(|(&count, &p)| {
+            let expected = n as f64 * p;
+            let diff = count as f64 - expected;
+            diff * diff / expected
+        })
+        .sum();
+    chi_square_p_value(chi_square, counts.len() - 1)
+}
+
+/// Probability that a chi-square distributed variable with `df` degrees of freedom is at least
+/// `x`.
+pub fn chi_square_p_value(x: f64, df: usize) -> f64 {
+    gamma_q(df as f64 / 2.0, x / 2.0)
+}
+
+/// Probability that a standard normal variable is at least as far from 0 as `z`, in either
+/// direction.
+pub fn normal_p_value(z: f64) -> f64 {
+    // erfc(|z|/sqrt(2)) = Q(1/2, z^2/2)
+    gamma_q(0.5, z * z / 2.0)
+}
+
+/// Two-sided p-value of an observation `k` of a Poisson distributed variable with mean `lambda`.
+///
+/// It is twice the smaller of the probabilities of a result at least `k` and at most `k`, up to
+/// a maximum of 1.
+pub fn poisson_p_value(k: u64, lambda: f64) -> f64 {
+    // P(X <= k) = Q(k + 1, lambda), and P(X >= k) = P(k, lambda).
+    let at_most = gamma_q(k as f64 + 1.0, lambda);
+    let at_least = if k == 0 {
+        1.0
+    } else {
+        gamma_p(k as f64, lambda)
+    };
+    (2.0 * at_most.min(at_least)).min(1.0)
+}
+
+const GAMMA_EPSILON: f64 = 1e-15;
+const GAMMA_MAX_ITERATIONS: usize = 100_000;
+
+/// Natural logarithm of the gamma function, for x > 0, by the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    let x = x - 1.0;
+    let t = x + G + 0.5;
+    let a = COEFFICIENTS
+        .iter()
+        .enumerate()
+        .skip(1)
+        .fold(COEFFICIENTS[0], |a, (i, c)| a + c / (x + i as f64));
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + a.ln()
+}
+
+/// Regularised lower incomplete gamma function P(a, x).
+fn gamma_p(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        0.0
+    } else if x < a + 1.0 {
+        gamma_p_series(a, x)
+    } else {
+        1.0 - gamma_q_continued_fraction(a, x)
+    }
+}
+
+/// Regularised upper incomplete gamma function Q(a, x) = 1 - P(a, x).
+fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        1.0
+    } else if x < a + 1.0 {
+        1.0 - gamma_p_series(a, x)
+    } else {
+        gamma_q_continued_fraction(a, x)
+    }
+}
+
+/// P(a, x) by its series expansion, which converges quickly for x < a + 1.
+fn gamma_p_series(a: f64, x: f64) -> f64 {
+    let mut term = 1.0 / a;
+    let mut sum = term;
+    let mut ap = a;
+    for _ in 0..GAMMA_MAX_ITERATIONS {
+        ap += 1.0;
+        term *= x / ap;
+        sum += term;
+        if term.abs() < sum.abs() * GAMMA_EPSILON {
+            break;
+        }
+    }
+    sum * (-x + a * x.ln() - ln_gamma(a)).exp()
+}
+
+/// Q(a, x) by its continued fraction, evaluated by the modified Lentz method, which converges
+/// quickly for x > a + 1.
+fn gamma_q_continued_fraction(a: f64, x: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..GAMMA_MAX_ITERATIONS {
+        let an = -(i as f64) * (i as f64 - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = b + an / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < GAMMA_EPSILON {
+            break;
+        }
+    }
+    (-x + a * x.ln() - ln_gamma(a)).exp() * h
+}
@@ -0,0 +1,69 @@
+use ::simplerandom::biguint::BigUint;
+
+use num_traits::Zero;
+
+#[test]
+fn test_from_u64() {
+    assert_eq!(BigUint::from_u64(0), BigUint::zero());
+    assert_eq!(BigUint::from_u64(0x123456789).limbs(), &[0x23456789, 1]);
+    assert_eq!(BigUint::from_u64(0x123456789).to_u64(), Some(0x123456789));
+    assert_eq!(BigUint::from_limbs(&[1, 0, 0]).limbs(), &[1]);
+    assert_eq!(BigUint::from_limbs(&[0, 0, 1]).to_u64(), None);
+    assert_eq!(BigUint::from_limbs(&[0, 0, 1]).bits(), 65);
+}
+
+#[test]
+fn test_add_sub_mul() {
+    let a = BigUint::from_u64(u64::MAX);
+    let b = BigUint::from_u64(1);
+    let sum = &a + &b;
+    assert_eq!(sum, BigUint::from_limbs(&[0, 0, 1]));
+    assert_eq!(&sum - &b, a);
+    assert_eq!(&a * &a, BigUint::from_limbs(&[1, 0, 0xFFFFFFFE, 0xFFFFFFFF]));
+    assert!(a < sum);
+}
+
+#[test]
+fn test_div_rem() {
+    // (2^64-1)^2 = (2^64-1) (2^64-1) + 0
+    let a = BigUint::from_u64(u64::MAX);
+    let a2 = &a * &a;
+    assert_eq!(a2.div_rem(&a), (a.clone(), BigUint::zero()));
+    // Small divisor
+    assert_eq!(a.div_rem(&BigUint::from_u64(10)), (BigUint::from_u64(u64::MAX / 10), BigUint::from_u64(5)));
+    // Check q d + r = n, with r < d, for a range of awkward values.
+    let n = BigUint::from_limbs(&[0xFFFFFFFF, 0, 0x80000000, 0xFFFFFFFF, 0x12345678, 1]);
+    for d_limbs in [[1, 0x80000000, 0], [0xFFFFFFFF, 0xFFFFFFFF, 0], [5, 7, 1], [0, 0, 0x80000000]].iter() {
+        let d = BigUint::from_limbs(d_limbs);
+        let (q, r) = n.div_rem(&d);
+        assert!(r < d);
+        assert_eq!(&(&q * &d) + &r, n);
+    }
+}
+
+#[test]
+fn test_pow_mod() {
+    // Compare with the primitive integer pow_mod.
+    let m = 4294967291_u64;
+    let result = BigUint::from_u64(123456789).pow_mod(1_000_000_000_000, &BigUint::from_u64(m));
+    assert_eq!(result.to_u64(), Some(simplerandom::maths::pow_mod(123456789_u64, 1_000_000_000_000_u64, m)));
+    // Fermat's little theorem, for the Mersenne prime 2^127-1.
+    let m = &BigUint::from_u64(2).pow(127) - &BigUint::from_u64(1);
+    let result = BigUint::from_u64(3).pow_mod((1 << 127) - 2, &m);
+    assert_eq!(result, BigUint::from_u64(1));
+}
+
+#[test]
+fn test_gcd_lcm() {
+    let a = BigUint::from_u64(2 * 3 * 3 * 5 * 7);
+    let b = BigUint::from_u64(3 * 5 * 5 * 11);
+    assert_eq!(a.gcd(&b), BigUint::from_u64(15));
+    assert_eq!(a.lcm(&b), BigUint::from_u64(2 * 3 * 3 * 5 * 5 * 7 * 11));
+    assert_eq!(a.gcd(&BigUint::zero()), a);
+    // gcd(2^a-1, 2^b-1) = 2^gcd(a,b)-1
+    let one = BigUint::from_u64(1);
+    let m96 = &BigUint::from_u64(2).pow(96) - &one;
+    let m120 = &BigUint::from_u64(2).pow(120) - &one;
+    assert_eq!(m96.gcd(&m120), &BigUint::from_u64(2).pow(24) - &one);
+    assert_eq!(BigUint::from_u128(u128::MAX), &BigUint::from_u64(2).pow(128) - &one);
+}
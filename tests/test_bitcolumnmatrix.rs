@@ -1,7 +1,11 @@
 use ::simplerandom::bitcolumnmatrix::BitColumnMatrix;
+use ::simplerandom::gf2poly::Gf2Poly;
+use ::simplerandom::{RngJumpAhead, TauswortheComponent32, Xorshift16, SHR3};
 
 use num_traits::{One, Pow, Zero};
+use rand_core::RngCore;
 
+type BitColumnMatrix16 = BitColumnMatrix<u16, 16>;
 type BitColumnMatrix32 = BitColumnMatrix<u32, 32>;
 
 #[test]
@@ -11,7 +15,7 @@ fn test_zero() {
     while x != 0 {
         let mul_result = zero.dot_vec(x);
         assert_eq!(mul_result, 0);
-        x = x >> 1;
+        x >>= 1;
     }
 }
 
@@ -22,7 +26,7 @@ fn test_one() {
     while x != 0 {
         let mul_result = one.dot_vec(x);
         assert_eq!(mul_result, x);
-        x = x >> 1;
+        x >>= 1;
     }
 }
 
@@ -83,7 +87,7 @@ fn test_shift() {
                 let shift_right_by = -shift_by;
                 assert_eq!(mul_result, x >> shift_right_by);
             }
-            x = x >> 1;
+            x >>= 1;
         }
     }
 }
@@ -100,7 +104,7 @@ fn test_bitand() {
     while x != 0 {
         let mul_result = mask_matrix.dot_vec(x);
         assert_eq!(mul_result, x & mask);
-        x = x >> 1;
+        x >>= 1;
     }
 }
 
@@ -168,3 +172,351 @@ fn test_lfsr113_1_matrix() {
 
     assert_eq!(lfsr88_z1_matrix, built_lfsr88_z1_matrix);
 }
+
+#[test]
+fn test_from_linear_fn() {
+    assert_eq!(
+        BitColumnMatrix32::from_linear_fn(|x| x),
+        BitColumnMatrix32::one()
+    );
+    assert_eq!(
+        BitColumnMatrix32::from_linear_fn(|_| 0),
+        BitColumnMatrix32::zero()
+    );
+    assert_eq!(
+        BitColumnMatrix32::from_linear_fn(|x| x << 13),
+        BitColumnMatrix32::shift(13)
+    );
+    assert_eq!(
+        BitColumnMatrix32::from_linear_fn(|x| x >> 7),
+        BitColumnMatrix32::shift(-7)
+    );
+
+    // The matrix gives the same result as the function, for any value.
+    let f = |x: u32| x ^ (x << 5) ^ (x >> 3);
+    let matrix = BitColumnMatrix32::from_linear_fn(f);
+    for x in [
+        0x00000001_u32,
+        0x12345678,
+        0x80000000,
+        0xFFFFFFFF,
+        0xDEADBEEF,
+    ] {
+        assert_eq!(matrix.dot_vec(x), f(x));
+    }
+}
+
+// The generators derive their jumpahead matrices from their steps with from_linear_fn. The
+// following tests check matrices derived in the same way against known tables.
+
+#[test]
+fn test_shr3_matrix_from_fn() {
+    const SHR3_MATRIX_ARRAY: [u32; 32] = [
+        0x00042021, 0x00084042, 0x00108084, 0x00210108, 0x00420231, 0x00840462, 0x010808C4,
+        0x02101188, 0x04202310, 0x08404620, 0x10808C40, 0x21011880, 0x42023100, 0x84046200,
+        0x0808C400, 0x10118800, 0x20231000, 0x40462021, 0x808C4042, 0x01080084, 0x02100108,
+        0x04200210, 0x08400420, 0x10800840, 0x21001080, 0x42002100, 0x84004200, 0x08008400,
+        0x10010800, 0x20021000, 0x40042000, 0x80084000,
+    ];
+    let shr3_matrix = BitColumnMatrix32::from_linear_fn(|z| SHR3::new(z).next_u32());
+
+    assert_eq!(shr3_matrix, BitColumnMatrix32::new(&SHR3_MATRIX_ARRAY));
+}
+
+#[test]
+fn test_lfsr88_matrices_from_fn() {
+    const LFSR88_Z1_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00002000, 0x00004000, 0x00008000, 0x00010000, 0x00020000, 0x00040001,
+        0x00080002, 0x00100004, 0x00200008, 0x00400010, 0x00800020, 0x01000040, 0x02000080,
+        0x04000100, 0x08000200, 0x10000400, 0x20000800, 0x40001000, 0x80000001, 0x00000002,
+        0x00000004, 0x00000008, 0x00000010, 0x00000020, 0x00000040, 0x00000080, 0x00000100,
+        0x00000200, 0x00000400, 0x00000800, 0x00001000,
+    ];
+    const LFSR88_Z2_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00000000, 0x00000000, 0x00000080, 0x00000100, 0x00000200, 0x00000400,
+        0x00000800, 0x00001000, 0x00002000, 0x00004000, 0x00008000, 0x00010000, 0x00020000,
+        0x00040000, 0x00080000, 0x00100000, 0x00200000, 0x00400000, 0x00800000, 0x01000000,
+        0x02000000, 0x04000000, 0x08000001, 0x10000002, 0x20000005, 0x4000000A, 0x80000014,
+        0x00000028, 0x00000050, 0x00000020, 0x00000040,
+    ];
+    const LFSR88_Z3_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00200000, 0x00400000, 0x00800000,
+        0x01000000, 0x02000001, 0x04000002, 0x08000004, 0x10000009, 0x20000012, 0x40000024,
+        0x80000048, 0x00000090, 0x00000120, 0x00000240, 0x00000480, 0x00000900, 0x00001200,
+        0x00002400, 0x00004800, 0x00009000, 0x00012000, 0x00024000, 0x00048000, 0x00090000,
+        0x00120000, 0x00040000, 0x00080000, 0x00100000,
+    ];
+    let z1 = TauswortheComponent32::new(31, 13, 12, 1).unwrap();
+    let z2 = TauswortheComponent32::new(29, 2, 4, 1).unwrap();
+    let z3 = TauswortheComponent32::new(28, 3, 17, 1).unwrap();
+
+    assert_eq!(
+        *z1.matrix(),
+        BitColumnMatrix32::new(&LFSR88_Z1_MATRIX_ARRAY)
+    );
+    assert_eq!(
+        *z2.matrix(),
+        BitColumnMatrix32::new(&LFSR88_Z2_MATRIX_ARRAY)
+    );
+    assert_eq!(
+        *z3.matrix(),
+        BitColumnMatrix32::new(&LFSR88_Z3_MATRIX_ARRAY)
+    );
+}
+
+#[test]
+fn test_lfsr113_matrices_from_fn() {
+    const LFSR113_Z1_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00080000, 0x00100000, 0x00200000, 0x00400000, 0x00800000, 0x01000000,
+        0x02000001, 0x04000002, 0x08000004, 0x10000008, 0x20000010, 0x40000020, 0x80000041,
+        0x00000082, 0x00000104, 0x00000208, 0x00000410, 0x00000820, 0x00001040, 0x00002080,
+        0x00004100, 0x00008200, 0x00010400, 0x00020800, 0x00041000, 0x00002000, 0x00004000,
+        0x00008000, 0x00010000, 0x00020000, 0x00040000,
+    ];
+    const LFSR113_Z2_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00000000, 0x00000000, 0x00000020, 0x00000040, 0x00000080, 0x00000100,
+        0x00000200, 0x00000400, 0x00000800, 0x00001000, 0x00002000, 0x00004000, 0x00008000,
+        0x00010000, 0x00020000, 0x00040000, 0x00080000, 0x00100000, 0x00200000, 0x00400000,
+        0x00800000, 0x01000000, 0x02000000, 0x04000000, 0x08000001, 0x10000002, 0x20000005,
+        0x4000000A, 0x80000014, 0x00000008, 0x00000010,
+    ];
+    const LFSR113_Z3_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000800, 0x00001000, 0x00002000,
+        0x00004000, 0x00008001, 0x00010002, 0x00020004, 0x00040008, 0x00080010, 0x00100020,
+        0x00200040, 0x00400080, 0x00800100, 0x01000200, 0x02000400, 0x04000000, 0x08000000,
+        0x10000001, 0x20000002, 0x40000004, 0x80000008, 0x00000010, 0x00000020, 0x00000040,
+        0x00000080, 0x00000100, 0x00000200, 0x00000400,
+    ];
+    const LFSR113_Z4_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00100000, 0x00200000, 0x00400001, 0x00800002, 0x01000004, 0x02000009, 0x04000012,
+        0x08000024, 0x10000048, 0x20000090, 0x40000120, 0x80000240, 0x00000480, 0x00000900,
+        0x00001200, 0x00002400, 0x00004800, 0x00009000, 0x00012000, 0x00024000, 0x00048000,
+        0x00090000, 0x00020000, 0x00040000, 0x00080000,
+    ];
+    let z1 = TauswortheComponent32::new(31, 6, 18, 1).unwrap();
+    let z2 = TauswortheComponent32::new(29, 2, 2, 1).unwrap();
+    let z3 = TauswortheComponent32::new(28, 13, 7, 1).unwrap();
+    let z4 = TauswortheComponent32::new(25, 3, 13, 1).unwrap();
+
+    assert_eq!(
+        *z1.matrix(),
+        BitColumnMatrix32::new(&LFSR113_Z1_MATRIX_ARRAY)
+    );
+    assert_eq!(
+        *z2.matrix(),
+        BitColumnMatrix32::new(&LFSR113_Z2_MATRIX_ARRAY)
+    );
+    assert_eq!(
+        *z3.matrix(),
+        BitColumnMatrix32::new(&LFSR113_Z3_MATRIX_ARRAY)
+    );
+    assert_eq!(
+        *z4.matrix(),
+        BitColumnMatrix32::new(&LFSR113_Z4_MATRIX_ARRAY)
+    );
+}
+
+#[test]
+fn test_xorshift16_matrix_from_fn() {
+    const XORSHIFT16_MATRIX_ARRAY: [u16; 16] = [
+        0x8181, 0x0302, 0x0705, 0x0E0A, 0x1C14, 0x3828, 0x7050, 0xE0A0, 0xC140, 0x0301, 0x0602,
+        0x0C04, 0x1808, 0x3010, 0x6020, 0xC040,
+    ];
+    let xorshift16_matrix = BitColumnMatrix16::from_linear_fn(|x| Xorshift16::new(x).next_u16());
+
+    assert_eq!(
+        xorshift16_matrix,
+        BitColumnMatrix16::new(&XORSHIFT16_MATRIX_ARRAY)
+    );
+}
+
+/// Evaluate polynomial `p` at matrix `m`.
+fn poly_at_matrix(p: &Gf2Poly, m: &BitColumnMatrix32) -> BitColumnMatrix32 {
+    let mut result = BitColumnMatrix32::zero();
+    let mut m_pow = BitColumnMatrix32::one();
+    for i in 0..=p.degree().unwrap_or(0) {
+        if p.coefficient(i) {
+            result = result + m_pow.clone();
+        }
+        m_pow = m_pow.dot(m);
+    }
+    result
+}
+
+#[test]
+fn test_char_poly() {
+    // (x + 1)^32 = x^32 + 1
+    assert_eq!(
+        BitColumnMatrix32::one().char_poly(),
+        Gf2Poly::from_words(&[0x100000001])
+    );
+    assert_eq!(BitColumnMatrix32::zero().char_poly(), Gf2Poly::x().pow(32));
+    // A shift is nilpotent.
+    assert_eq!(
+        BitColumnMatrix32::shift(5).char_poly(),
+        Gf2Poly::x().pow(32)
+    );
+
+    // Cayley–Hamilton: every matrix satisfies its characteristic polynomial.
+    let f = |x: u32| x ^ (x << 5) ^ (x >> 3) ^ (x.rotate_left(11) & 0x0F0F0F0F);
+    let matrix = BitColumnMatrix32::from_linear_fn(f);
+    let p = matrix.char_poly();
+    assert_eq!(p.degree(), Some(32));
+    assert_eq!(poly_at_matrix(&p, &matrix), BitColumnMatrix32::zero());
+}
+
+#[test]
+fn test_shr3_char_poly_primitive() {
+    let shr3_matrix = BitColumnMatrix32::from_linear_fn(|z| SHR3::new(z).next_u32());
+    let p = shr3_matrix.char_poly();
+    assert_eq!(p.degree(), Some(32));
+    assert!(p.is_primitive(&[3, 5, 17, 257, 65537]));
+    assert_eq!(poly_at_matrix(&p, &shr3_matrix), BitColumnMatrix32::zero());
+
+    // Berlekamp–Massey on one bit of the output finds the same polynomial.
+    let mut rng = SHR3::new(1);
+    let bits: Vec<bool> = (0..64).map(|_| rng.next_u32() & 1 != 0).collect();
+    assert_eq!(Gf2Poly::minimal_polynomial(&bits), p);
+}
+
+#[test]
+fn test_lfsr_char_poly_primitive() {
+    // (k, q, s) of the LFSR88 and LFSR113 components, with the prime factors of 2^k-1.
+    let components: [(u32, u32, u32, &[u128]); 7] = [
+        (31, 13, 12, &[2147483647]),
+        (29, 2, 4, &[233, 1103, 2089]),
+        (28, 3, 17, &[3, 5, 29, 43, 113, 127]),
+        (31, 6, 18, &[2147483647]),
+        (29, 2, 2, &[233, 1103, 2089]),
+        (28, 13, 7, &[3, 5, 29, 43, 113, 127]),
+        (25, 3, 13, &[31, 601, 1801]),
+    ];
+    for (k, q, s, factors) in components.iter() {
+        let component = TauswortheComponent32::new(*k, *q, *s, 1).unwrap();
+        // The least significant 32-k bits aren't part of the recurrence, and contribute a factor
+        // x^(32-k) to the characteristic polynomial.
+        let dead_bits = 32 - *k as usize;
+        let char_poly = component.matrix().char_poly();
+        let p = char_poly.shr(dead_bits);
+        assert_eq!(p.shl(dead_bits), char_poly);
+        assert_eq!(p.degree(), Some(*k as usize));
+        assert!(p.is_primitive(factors));
+    }
+}
+
+#[test]
+fn test_transpose() {
+    assert_eq!(
+        BitColumnMatrix32::one().transpose(),
+        BitColumnMatrix32::one()
+    );
+    assert_eq!(
+        BitColumnMatrix32::shift(7).transpose(),
+        BitColumnMatrix32::shift(-7)
+    );
+    let matrix = BitColumnMatrix32::from_linear_fn(|x| x ^ (x << 5) ^ (x >> 3));
+    assert_eq!(matrix.transpose().transpose(), matrix);
+    // (AB)^T = B^T A^T
+    let shr3_matrix = BitColumnMatrix32::from_linear_fn(|z| SHR3::new(z).next_u32());
+    assert_eq!(
+        matrix.dot(&shr3_matrix).transpose(),
+        shr3_matrix.transpose().dot(&matrix.transpose())
+    );
+}
+
+#[test]
+fn test_rank_determinant() {
+    assert_eq!(BitColumnMatrix32::one().rank(), 32);
+    assert_eq!(BitColumnMatrix32::zero().rank(), 0);
+    assert_eq!(BitColumnMatrix32::shift(5).rank(), 27);
+    assert_eq!(BitColumnMatrix32::shift(-31).rank(), 1);
+    assert!(BitColumnMatrix32::one().determinant());
+    assert!(!BitColumnMatrix32::shift(1).determinant());
+
+    // The LFSR component matrices have rank k, since the least significant 32-k bits don't
+    // affect the result.
+    let z1 = TauswortheComponent32::new(31, 13, 12, 1).unwrap();
+    assert_eq!(z1.matrix().rank(), 31);
+    assert!(!z1.matrix().determinant());
+    assert_eq!(z1.matrix().transpose().rank(), 31);
+}
+
+#[test]
+fn test_inverse() {
+    assert_eq!(
+        BitColumnMatrix32::one().inverse(),
+        Some(BitColumnMatrix32::one())
+    );
+    assert_eq!(BitColumnMatrix32::shift(1).inverse(), None);
+    assert_eq!(BitColumnMatrix32::zero().inverse(), None);
+
+    // SHR3's step must be invertible, to be a permutation of the states.
+    let shr3_matrix = BitColumnMatrix32::from_linear_fn(|z| SHR3::new(z).next_u32());
+    assert!(shr3_matrix.determinant());
+    let shr3_inverse = shr3_matrix.inverse().unwrap();
+    assert_eq!(shr3_inverse.dot(&shr3_matrix), BitColumnMatrix32::one());
+    assert_eq!(shr3_matrix.dot(&shr3_inverse), BitColumnMatrix32::one());
+    // The inverse steps backwards, the same as jumpahead by -1.
+    let mut s = SHR3::new(0x12345678);
+    let z = s.next_u32();
+    assert_eq!(shr3_inverse.dot_vec(z), 0x12345678);
+    let mut s = SHR3::new(z);
+    s.jumpahead(-1);
+    assert_eq!(s, SHR3::new(0x12345678));
+    assert_eq!(shr3_inverse, shr3_matrix.pow(0xFFFFFFFE_u32));
+}
+
+#[test]
+fn test_solve() {
+    let shr3_matrix = BitColumnMatrix32::from_linear_fn(|z| SHR3::new(z).next_u32());
+    for x in [0x00000001_u32, 0x12345678, 0x80000000, 0xFFFFFFFF] {
+        let b = shr3_matrix.dot_vec(x);
+        assert_eq!(shr3_matrix.solve(b), Some(x));
+    }
+    assert_eq!(BitColumnMatrix32::shift(3).solve(8), None);
+
+    let matrix = BitColumnMatrix::<u16, 16>::from_linear_fn(|x| x ^ (x << 1));
+    assert_eq!(matrix.solve(0b110), Some(0b010));
+}
+
+/// Check that the Four Russians multiplication gives the same results as the simple method.
+fn check_dot_four_russians<T, const WIDTH: usize>(seed: u64)
+where
+    T: simplerandom::bitcolumnmatrix::BitColumnMatrixInt + std::fmt::Debug,
+{
+    let mut rng = simplerandom::SplitMix64::new(seed);
+    let mut random = || -> T {
+        let value = ((rng.next_u64() as u128) << 64) | rng.next_u64() as u128;
+        T::from(value & (u128::MAX >> (128 - WIDTH))).unwrap()
+    };
+    for _ in 0..10 {
+        let a = BitColumnMatrix::<T, WIDTH>::new(&[(); WIDTH].map(|_| random()));
+        let b = BitColumnMatrix::<T, WIDTH>::new(&[(); WIDTH].map(|_| random()));
+        assert_eq!(a.dot(&b), a.dot_naive(&b));
+        let mut c = a.clone();
+        c.dot_equ(&b);
+        assert_eq!(c, a.dot_naive(&b));
+
+        let table = a.dot_table();
+        for _ in 0..10 {
+            let x = random();
+            assert_eq!(table.dot_vec(x), a.dot_vec(x));
+        }
+        // Bits beyond the matrix width are ignored.
+        assert_eq!(table.dot_vec(T::max_value()), a.dot_vec(T::max_value()));
+    }
+}
+
+#[test]
+fn test_dot_four_russians() {
+    check_dot_four_russians::<u8, 8>(1);
+    check_dot_four_russians::<u16, 16>(2);
+    check_dot_four_russians::<u32, 32>(3);
+    check_dot_four_russians::<u64, 64>(4);
+    check_dot_four_russians::<u128, 128>(5);
+    // Widths that aren't a multiple of 8, or less than the word size.
+    check_dot_four_russians::<u32, 20>(6);
+    check_dot_four_russians::<u16, 13>(7);
+    check_dot_four_russians::<u128, 100>(8);
+}
@@ -0,0 +1,201 @@
+use ::simplerandom::bitcolumnmatrix::BitColumnMatrix;
+use ::simplerandom::bitmatrix::BitMatrix;
+use ::simplerandom::{RngJumpAhead, TauswortheComponent32, LFSR113, SHR3};
+
+use num_traits::{One, Pow, Zero};
+use rand_core::RngCore;
+
+type BitMatrix32 = BitMatrix<32, 32>;
+type BitMatrix128 = BitMatrix<128, 128>;
+type BitMatrix256 = BitMatrix<256, 256>;
+
+#[test]
+fn test_zero_one() {
+    let zero = BitMatrix256::zero();
+    let one = BitMatrix256::one();
+    assert!(zero.is_zero());
+    assert!(!one.is_zero());
+    let v = [
+        0x0123456789ABCDEF,
+        0xFEDCBA9876543210,
+        0xDEADBEEF,
+        0x8000000000000001,
+    ];
+    assert_eq!(zero.dot_vec(&v), vec![0; 4]);
+    assert_eq!(one.dot_vec(&v), v.to_vec());
+    assert_eq!(one.dot(&one), one);
+    assert!(one.get(200, 200));
+    assert!(!one.get(200, 201));
+}
+
+#[test]
+fn test_odd_size() {
+    // Dimensions that aren't a multiple of the block size.
+    type BitMatrix100 = BitMatrix<100, 100>;
+    let shift = BitMatrix100::from_linear_fn(|v| vec![v[0] << 1, (v[1] << 1) | (v[0] >> 63)]);
+    // The top bit is shifted out.
+    assert_eq!(shift.dot_vec(&[0, 1 << 35]), vec![0, 0]);
+    assert_eq!(shift.dot_vec(&[1 << 63, 1]), vec![0, 3]);
+    assert_eq!(shift.clone().pow(99_u32).dot_vec(&[1, 0]), vec![0, 1 << 35]);
+    assert!(shift.pow(100_u32).is_zero());
+}
+
+#[test]
+fn test_shr3_matches_bitcolumnmatrix() {
+    let shr3_matrix = BitColumnMatrix::<u32, 32>::from_linear_fn(|z| SHR3::new(z).next_u32());
+    let shr3_bitmatrix =
+        BitMatrix32::from_linear_fn(|v| vec![SHR3::new(v[0] as u32).next_u32() as u64]);
+    for c in 0..32 {
+        assert_eq!(
+            shr3_bitmatrix.column(c)[0],
+            shr3_matrix.dot_vec(1 << c) as u64
+        );
+    }
+    let pow_matrix = shr3_matrix.pow(1_000_000_000_u32);
+    let pow_bitmatrix = shr3_bitmatrix.pow(1_000_000_000_u32);
+    for x in [1_u32, 0x12345678, 0xFFFFFFFF] {
+        assert_eq!(
+            pow_bitmatrix.dot_vec(&[x as u64]),
+            vec![pow_matrix.dot_vec(x) as u64]
+        );
+    }
+}
+
+/// The transition matrix of the four 32-bit LFSR113 components, as one 128-bit state.
+fn lfsr113_transition() -> BitMatrix128 {
+    let components = [
+        TauswortheComponent32::new(31, 6, 18, 1).unwrap(),
+        TauswortheComponent32::new(29, 2, 2, 1).unwrap(),
+        TauswortheComponent32::new(28, 13, 7, 1).unwrap(),
+        TauswortheComponent32::new(25, 3, 13, 1).unwrap(),
+    ];
+    BitMatrix128::from_linear_fn(|v| {
+        let z: Vec<u32> = (0..4)
+            .map(|i| {
+                components[i]
+                    .matrix()
+                    .dot_vec((v[i / 2] >> (32 * (i % 2))) as u32)
+            })
+            .collect();
+        vec![
+            z[0] as u64 | ((z[1] as u64) << 32),
+            z[2] as u64 | ((z[3] as u64) << 32),
+        ]
+    })
+}
+
+/// The LFSR113 output function, the xor of the four components, over the 128-bit state.
+fn lfsr113_output() -> BitMatrix<32, 128> {
+    BitMatrix::<32, 128>::from_linear_fn(|v| {
+        vec![(v[0] ^ (v[0] >> 32) ^ v[1] ^ (v[1] >> 32)) & 0xFFFFFFFF]
+    })
+}
+
+/// The LFSR113 128-bit state for `LFSR113::new(1, 2, 3, 4)`.
+fn lfsr113_state() -> [u64; 2] {
+    let seed = |s: u64| s ^ (s << 16);
+    [seed(1) | (seed(2) << 32), seed(3) | (seed(4) << 32)]
+}
+
+#[test]
+fn test_lfsr113_combined_state() {
+    let matrix = lfsr113_transition();
+    let state = lfsr113_state();
+    for n in [0_u64, 1, 1000, 1_000_000_000_000_000_000] {
+        let mut rng = LFSR113::new(1, 2, 3, 4);
+        rng.jumpahead(n);
+        let v = matrix.clone().pow(n + 1).dot_vec(&state);
+        let output = v[0] ^ (v[0] >> 32) ^ v[1] ^ (v[1] >> 32);
+        assert_eq!(output as u32, rng.next_u32());
+    }
+    // Each component's transition has rank k, since the other bits don't affect the result.
+    assert_eq!(matrix.rank(), 31 + 29 + 28 + 25);
+}
+
+#[test]
+fn test_lfsr113_output_map() {
+    let transition = lfsr113_transition();
+    let output = lfsr113_output();
+    assert_eq!(output.rank(), 32);
+    assert_eq!(output.transpose().rank(), 32);
+
+    // The output after n + 1 steps, as a function of the initial state.
+    let state = lfsr113_state();
+    let mut rng = LFSR113::new(1, 2, 3, 4);
+    let mut output_n = output.dot(&transition);
+    for _ in 0..100 {
+        assert_eq!(output_n.dot_vec(&state), vec![rng.next_u32() as u64]);
+        output_n = output_n.dot(&transition);
+    }
+    let output_n = &output * &transition.pow(1_000_001_u32);
+    rng.jumpahead(1_000_000 - 100);
+    assert_eq!(output_n.dot_vec(&state), vec![rng.next_u32() as u64]);
+}
+
+#[test]
+fn test_rectangular() {
+    // 3×5 matrix: rows are bits of the result.
+    let a = BitMatrix::<3, 5>::from_linear_fn(|v| {
+        let x = v[0];
+        vec![(x & 1) | ((x >> 2) & 2) | (((x >> 1) ^ (x >> 3)) & 1) << 2]
+    });
+    assert_eq!(a.dot_vec(&[0b00001]), vec![0b001]);
+    assert_eq!(a.dot_vec(&[0b01000]), vec![0b110]);
+    assert_eq!(a.dot_vec(&[0b01010]), vec![0b010]);
+    assert_eq!(a.rank(), 3);
+    // 5×2 matrix.
+    let b = BitMatrix::<5, 2>::from_linear_fn(|v| vec![(v[0] & 3) * 0b01001]);
+    let ab: BitMatrix<3, 2> = a.dot(&b);
+    for x in 0..4 {
+        assert_eq!(ab.dot_vec(&[x]), a.dot_vec(&b.dot_vec(&[x])));
+    }
+    assert_eq!(ab, a.clone() * b.clone());
+    // (AB)^T = B^T A^T
+    assert_eq!(ab.transpose(), b.transpose().dot(&a.transpose()));
+    assert!(a.get(2, 3));
+    assert!(a.transpose().get(3, 2));
+}
+
+fn xoshiro256_step(s: &mut [u64; 4]) {
+    let t = s[1] << 17;
+    s[2] ^= s[0];
+    s[3] ^= s[1];
+    s[1] ^= s[2];
+    s[0] ^= s[3];
+    s[2] ^= t;
+    s[3] = s[3].rotate_left(45);
+}
+
+#[test]
+fn test_xoshiro256_jump() {
+    let matrix = BitMatrix256::from_linear_fn(|v| {
+        let mut s = [v[0], v[1], v[2], v[3]];
+        xoshiro256_step(&mut s);
+        s.to_vec()
+    });
+
+    // Jump by 2^128, by the reference jump() function of xoshiro256.
+    const JUMP: [u64; 4] = [
+        0x180EC6D33CFD0ABA,
+        0xD5A61266F0C9392C,
+        0xA9582618E03FC9AA,
+        0x39ABDC4529B1661C,
+    ];
+    let start = [1, 2, 3, 4];
+    let mut s = start;
+    let mut jumped = [0_u64; 4];
+    for jump in JUMP.iter() {
+        for b in 0..64 {
+            if (jump >> b) & 1 != 0 {
+                for (j, x) in jumped.iter_mut().zip(s.iter()) {
+                    *j ^= *x;
+                }
+            }
+            xoshiro256_step(&mut s);
+        }
+    }
+
+    // M^(2^128) = (M^(2^64))^(2^64)
+    let matrix_2_128 = matrix.pow(1_u128 << 64).pow(1_u128 << 64);
+    assert_eq!(matrix_2_128.dot_vec(&start), jumped.to_vec());
+}
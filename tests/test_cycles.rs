@@ -0,0 +1,237 @@
+use ::simplerandom::cycles::{self, Cycle, CycleStructure, SmallMwc, XorshiftTriple};
+use ::simplerandom::{Cong, Cong16, Xorshift16, MWC8, SHR3};
+use rand_core::RngCore;
+use std::collections::HashMap;
+
+fn cong_step(x: u32) -> u32 {
+    x.wrapping_mul(69069).wrapping_add(12345)
+}
+
+fn cong16_step(x: u64) -> u64 {
+    (x * 25173 + 13849) & 0xFFFF
+}
+
+fn structure(cycles: &[(u128, u128)]) -> CycleStructure {
+    cycles.iter().copied().collect()
+}
+
+#[test]
+fn test_brent() {
+    // Compare with a simple search that records every state, for a function with tails.
+    let f = |x: u32| (x * x + 1) % 1009;
+    for x0 in 0..1009 {
+        let mut seen = HashMap::new();
+        let mut x = x0;
+        let mut i = 0_u128;
+        while !seen.contains_key(&x) {
+            seen.insert(x, i);
+            x = f(x);
+            i += 1;
+        }
+        let tail = seen[&x];
+        assert_eq!(
+            cycles::brent(x0, f),
+            Cycle {
+                tail,
+                length: i - tail
+            }
+        );
+    }
+}
+
+#[test]
+fn test_permutation_cycles() {
+    // Multiplication by 3 modulo 7 has order 6; 0 is fixed.
+    assert_eq!(
+        cycles::permutation_cycles(7, |x| x * 3 % 7),
+        structure(&[(1, 1), (6, 1)])
+    );
+    assert_eq!(
+        cycles::permutation_cycles(1 << 16, cong16_step),
+        structure(&[(1 << 16, 1)])
+    );
+}
+
+#[test]
+#[should_panic(expected = "not a permutation")]
+fn test_permutation_cycles_not_permutation() {
+    cycles::permutation_cycles(10, |x| x * x % 10);
+}
+
+#[test]
+fn test_cong_step() {
+    // The step functions used for the cycle checks match the generators.
+    let mut cong = Cong::new(1);
+    let mut x = 1;
+    for _ in 0..100 {
+        x = cong_step(x);
+        assert_eq!(cong.next_u32(), x);
+    }
+    let mut cong16 = Cong16::new(1);
+    let mut x = 1;
+    for _ in 0..100 {
+        x = cong16_step(x);
+        assert_eq!(cong16.next_u16() as u64, x);
+    }
+}
+
+#[test]
+fn test_xorshift_triple_step() {
+    let xorshift = XorshiftTriple::<16>::new(7, 9, 8);
+    let mut xorshift16 = Xorshift16::new(1);
+    let mut x = 1;
+    for _ in 0..100 {
+        x = xorshift.step(x);
+        assert_eq!(xorshift16.next_u16() as u64, x);
+    }
+    let shr3_triple = XorshiftTriple::<32>::new(13, 17, 5);
+    let mut shr3 = SHR3::new(1);
+    let mut x = 1;
+    for _ in 0..100 {
+        x = shr3_triple.step(x);
+        assert_eq!(shr3.next_u32() as u64, x);
+    }
+}
+
+#[test]
+fn test_xorshift_all_12_bit_triples() {
+    // Exhaustively check the predicted cycle structure for every shift triple.
+    let mut full_period = 0;
+    for a in 1..12 {
+        for b in 1..12 {
+            for c in 1..12 {
+                let xorshift = XorshiftTriple::<12>::new(a, b, c);
+                let cycles = xorshift.cycles();
+                assert_eq!(cycles, xorshift.predicted_cycles(), "{:?}", (a, b, c));
+                assert_eq!(cycles.iter().map(|(len, n)| len * n).sum::<u128>(), 1 << 12);
+                if cycles == structure(&[(1, 1), (0xFFF, 1)]) {
+                    full_period += 1;
+                }
+            }
+        }
+    }
+    assert!(full_period > 0);
+}
+
+#[test]
+fn test_xorshift_16_bit() {
+    // Xorshift16
+    let xorshift = XorshiftTriple::<16>::new(7, 9, 8);
+    assert_eq!(xorshift.cycles(), structure(&[(1, 1), (0xFFFF, 1)]));
+    assert_eq!(xorshift.predicted_cycles(), xorshift.cycles());
+    // A triple without a full period.
+    let xorshift = XorshiftTriple::<16>::new(1, 1, 1);
+    assert_eq!(xorshift.predicted_cycles(), xorshift.cycles());
+    assert!(xorshift.cycles().len() > 2);
+}
+
+#[test]
+fn test_shr3_triples() {
+    // The 2003 SHR3 triple has a single cycle of all non-zero states.
+    let xorshift = XorshiftTriple::<32>::new(13, 17, 5);
+    assert_eq!(
+        xorshift.predicted_cycles(),
+        structure(&[(1, 1), (0xFFFF_FFFF, 1)])
+    );
+
+    // The 1999 SHR3 triple has 64 cycles, including the zero state, some of them very short.
+    // Its characteristic polynomial is (x+1)^3 times irreducible factors of degrees 12 and 17.
+    let xorshift = XorshiftTriple::<32>::new(17, 13, 5);
+    let predicted = xorshift.predicted_cycles();
+    assert_eq!(
+        predicted,
+        structure(&[
+            (1, 2),
+            (2, 1),
+            (4, 1),
+            (585, 14),
+            (1170, 7),
+            (2340, 7),
+            (131071, 2),
+            (262142, 1),
+            (524284, 1),
+            (76676535, 14),
+            (153353070, 7),
+            (306706140, 7),
+        ])
+    );
+    assert_eq!(predicted.values().sum::<u128>(), 64);
+    assert_eq!(
+        predicted.iter().map(|(len, n)| len * n).sum::<u128>(),
+        1 << 32
+    );
+}
+
+#[test]
+fn test_small_mwc() {
+    // MWC8 is the 8-bit MWC with multiplier 249.
+    let mwc = SmallMwc::new(8, 249);
+    let mut mwc8 = MWC8::new(1);
+    let mut s = 1;
+    for _ in 0..100 {
+        s = mwc.step(s);
+        assert_eq!(mwc8.next_u8() as u64, s & 0xFF);
+    }
+    assert_eq!(mwc.cycles(), structure(&[(1, 2), (31871, 2)]));
+
+    // Exhaustively check the predicted cycle structure for every 8-bit multiplier, including those
+    // with composite moduli.
+    for a in 2..256 {
+        let mwc = SmallMwc::new(8, a);
+        assert_eq!(mwc.cycles(), mwc.predicted_cycles(), "{}", a);
+    }
+    for a in 2..1024 {
+        let mwc = SmallMwc::new(4, a);
+        assert_eq!(mwc.cycles(), mwc.predicted_cycles(), "{}", a);
+    }
+}
+
+#[cfg(feature = "slow-tests")]
+mod slow {
+    use super::*;
+
+    #[test]
+    fn test_cong_full_period() {
+        assert_eq!(
+            cycles::brent(1_u32, cong_step),
+            Cycle {
+                tail: 0,
+                length: 1 << 32
+            }
+        );
+    }
+
+    #[test]
+    fn test_shr3_full_period() {
+        let xorshift = XorshiftTriple::<32>::new(13, 17, 5);
+        assert_eq!(
+            cycles::brent(1_u64, |x| xorshift.step(x)),
+            Cycle {
+                tail: 0,
+                length: 0xFFFF_FFFF
+            }
+        );
+    }
+
+    #[test]
+    fn test_shr3_1999_exhaustive() {
+        let xorshift = XorshiftTriple::<32>::new(17, 13, 5);
+        assert_eq!(xorshift.cycles(), xorshift.predicted_cycles());
+    }
+
+    #[test]
+    fn test_mwc1_periods() {
+        // Each half of MWC1 is a 16-bit MWC whose modulus is prime.
+        for a in [36969, 18000] {
+            let mwc = SmallMwc::new(16, a);
+            let predicted = mwc.predicted_cycles();
+            let cycle = cycles::brent(1_u64, |s| mwc.step(s));
+            assert_eq!(cycle.tail, 0);
+            assert_eq!(predicted.len(), 2);
+            assert_eq!(
+                predicted.get(&cycle.length),
+                Some(&((mwc.modulus() as u128 - 1) / cycle.length))
+            );
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use ::simplerandom::equidistribution::Equidistribution;
+use ::simplerandom::TauswortheComponent32;
+
+#[test]
+fn test_lfsr88_me() {
+    let components = [
+        TauswortheComponent32::new(31, 13, 12, 1).unwrap(),
+        TauswortheComponent32::new(29, 2, 4, 1).unwrap(),
+        TauswortheComponent32::new(28, 3, 17, 1).unwrap(),
+    ];
+    let e = Equidistribution::combined_tausworthe::<96>(&components);
+    assert_eq!(e.k(), 88);
+    assert!(e.is_maximally_equidistributed());
+}
+
+#[test]
+fn test_lfsr113_me() {
+    let components = [
+        TauswortheComponent32::new(31, 6, 18, 1).unwrap(),
+        TauswortheComponent32::new(29, 2, 2, 1).unwrap(),
+        TauswortheComponent32::new(28, 13, 7, 1).unwrap(),
+        TauswortheComponent32::new(25, 3, 13, 1).unwrap(),
+    ];
+    let e = Equidistribution::combined_tausworthe::<128>(&components);
+    assert_eq!(e.k(), 113);
+    assert!(e.is_maximally_equidistributed());
+}
+
+#[test]
+fn test_single_component() {
+    // A single LFSR component isn't ME, which is why L'Ecuyer combines several.
+    let components = [TauswortheComponent32::new(28, 3, 17, 1).unwrap()];
+    let e = Equidistribution::combined_tausworthe::<32>(&components);
+    assert_eq!(e.k(), 28);
+    assert_eq!(e.dimension(1), 28);
+    assert_eq!(e.gap(1), 0);
+    assert_eq!(e.dimension(2), 13);
+    assert_eq!(e.dimension(4), 5);
+    assert_eq!(e.gap(4), 2);
+    assert_eq!(e.dimension(32), 0);
+    assert_eq!(e.gap(32), 0);
+    assert_eq!(e.total_gap(), 9);
+    assert!(!e.is_maximally_equidistributed());
+}
+
+#[test]
+#[should_panic(expected = "resolution must be from 1 to the output width")]
+fn test_resolution_zero() {
+    let components = [TauswortheComponent32::new(28, 3, 17, 1).unwrap()];
+    let e = Equidistribution::combined_tausworthe::<32>(&components);
+    e.dimension(0);
+}
+
+#[test]
+#[should_panic(expected = "resolution must be from 1 to the output width")]
+fn test_resolution_too_large() {
+    let components = [TauswortheComponent32::new(28, 3, 17, 1).unwrap()];
+    let e = Equidistribution::combined_tausworthe::<32>(&components);
+    e.gap(33);
+}
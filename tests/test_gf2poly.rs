@@ -0,0 +1,139 @@
+use ::simplerandom::gf2poly::Gf2Poly;
+
+use num_traits::Zero;
+
+#[test]
+fn test_degree() {
+    assert_eq!(Gf2Poly::zero().degree(), None);
+    assert_eq!(Gf2Poly::from_words(&[1]).degree(), Some(0));
+    assert_eq!(Gf2Poly::x().degree(), Some(1));
+    assert_eq!(Gf2Poly::from_words(&[0, 0x8000000000000000, 0]).degree(), Some(127));
+    assert_eq!(Gf2Poly::from_words(&[0, 0x8000000000000000, 0]).words().len(), 2);
+}
+
+#[test]
+fn test_shl_shr() {
+    let a = Gf2Poly::from_words(&[0x8000000000000001]);
+    assert_eq!(a.shl(1), Gf2Poly::from_words(&[0x0000000000000002, 1]));
+    assert_eq!(a.shl(64), Gf2Poly::from_words(&[0, 0x8000000000000001]));
+    assert_eq!(a.shl(65).shr(65), a);
+    assert_eq!(a.shr(1), Gf2Poly::from_words(&[0x4000000000000000]));
+    assert_eq!(a.shr(64), Gf2Poly::zero());
+}
+
+#[test]
+fn test_mul_square() {
+    // (x + 1)(x + 1) = x^2 + 1
+    let a = Gf2Poly::from_words(&[0b11]);
+    assert_eq!(a.mul(&a), Gf2Poly::from_words(&[0b101]));
+    assert_eq!(a.square(), Gf2Poly::from_words(&[0b101]));
+
+    // (x^2 + x + 1)(x + 1) = x^3 + 1
+    let b = Gf2Poly::from_words(&[0b111]);
+    assert_eq!(b.mul(&a), Gf2Poly::from_words(&[0b1001]));
+
+    let c = Gf2Poly::from_words(&[0x0123456789ABCDEF, 0xFEDCBA9876543210, 0x5A5A]);
+    assert_eq!(c.square(), c.mul(&c));
+}
+
+#[test]
+fn test_rem() {
+    // x^3 + 1 = (x + 1)(x^2 + x + 1)
+    let a = Gf2Poly::from_words(&[0b1001]);
+    let b = Gf2Poly::from_words(&[0b111]);
+    assert_eq!(a.rem(&b), Gf2Poly::zero());
+    // x^4 mod (x^2 + x + 1) = x
+    let a = Gf2Poly::from_words(&[0b10000]);
+    assert_eq!(a.rem(&b), Gf2Poly::x());
+}
+
+#[test]
+fn test_pow_mod() {
+    // x^4 + x + 1 is primitive, so x has order 15.
+    let m = Gf2Poly::from_words(&[0b10011]);
+    let one = Gf2Poly::from_words(&[1]);
+    assert_eq!(Gf2Poly::x().pow_mod(15, &m), one);
+    assert_eq!(Gf2Poly::x().pow_mod(5, &m), Gf2Poly::from_words(&[0b0110]));
+    assert_eq!(Gf2Poly::x().pow_mod(0, &m), one);
+    assert_eq!(Gf2Poly::x().pow_mod(15 * 1_000_000_007 + 5, &m), Gf2Poly::from_words(&[0b0110]));
+}
+
+#[test]
+fn test_inverse_mod() {
+    let m = Gf2Poly::from_words(&[0b10011]);
+    let one = Gf2Poly::from_words(&[1]);
+    for i in 1..16 {
+        let a = Gf2Poly::from_words(&[i]);
+        let a_inv = a.inverse_mod(&m).unwrap();
+        assert_eq!(a.mul_mod(&a_inv, &m), one);
+    }
+    // x^2 + 1 = (x + 1)^2 shares a factor with x + 1.
+    let m = Gf2Poly::from_words(&[0b101]);
+    assert_eq!(Gf2Poly::from_words(&[0b11]).inverse_mod(&m), None);
+}
+
+#[test]
+fn test_minimal_polynomial() {
+    // Sequence from the recurrence s[n] = s[n-3] + s[n-4], whose characteristic polynomial is
+    // x^4 + x + 1.
+    let mut bits = vec![true, false, false, false];
+    for n in 4..20 {
+        let bit = bits[n - 3] ^ bits[n - 4];
+        bits.push(bit);
+    }
+    assert_eq!(Gf2Poly::minimal_polynomial(&bits), Gf2Poly::from_words(&[0b10011]));
+
+    // Sequence 1, 0, 0, 1, 0, 0, ... that is periodic with period 3: the minimal polynomial is
+    // x^3 + 1.
+    let bits: Vec<bool> = (0..12).map(|i| i % 3 == 0).collect();
+    assert_eq!(Gf2Poly::minimal_polynomial(&bits), Gf2Poly::from_words(&[0b1001]));
+
+    // Sequence 0, 1, 1, 0, 1, 1, ... satisfies s[n] = s[n-1] + s[n-2]: the minimal polynomial is
+    // x^2 + x + 1.
+    let bits: Vec<bool> = (0..12).map(|i| i % 3 != 0).collect();
+    assert_eq!(Gf2Poly::minimal_polynomial(&bits), Gf2Poly::from_words(&[0b111]));
+
+    // All-zero sequence.
+    let bits = vec![false; 10];
+    assert_eq!(Gf2Poly::minimal_polynomial(&bits), Gf2Poly::from_words(&[1]));
+}
+
+#[test]
+fn test_pow() {
+    let a = Gf2Poly::from_words(&[0b11]);
+    assert_eq!(a.pow(0), Gf2Poly::from_words(&[1]));
+    assert_eq!(a.pow(1), a);
+    // (x + 1)^4 = x^4 + 1
+    assert_eq!(a.pow(4), Gf2Poly::from_words(&[0b10001]));
+    // (x + 1)^3 = x^3 + x^2 + x + 1
+    assert_eq!(a.pow(3), Gf2Poly::from_words(&[0b1111]));
+    let b = Gf2Poly::from_words(&[0x0123456789ABCDEF, 0x5A5A]);
+    assert_eq!(b.pow(5), b.square().square().mul(&b));
+}
+
+#[test]
+fn test_gcd() {
+    let a = Gf2Poly::from_words(&[0b11]);
+    let b = Gf2Poly::from_words(&[0b111]);
+    let c = Gf2Poly::from_words(&[0b10011]);
+    assert_eq!(a.mul(&b).gcd(&a.mul(&c)), a);
+    assert_eq!(b.mul(&c).gcd(&a.mul(&c)), c);
+    assert_eq!(a.gcd(&b), Gf2Poly::from_words(&[1]));
+    assert_eq!(a.gcd(&Gf2Poly::zero()), a);
+}
+
+#[test]
+fn test_is_primitive() {
+    // 2^4-1 = 3 * 5
+    let factors_15 = [3, 5];
+    // x^4 + x + 1 is primitive.
+    assert!(Gf2Poly::from_words(&[0b10011]).is_primitive(&factors_15));
+    // x^4 + x^3 + x^2 + x + 1 is irreducible, but x has order 5.
+    assert!(!Gf2Poly::from_words(&[0b11111]).is_primitive(&factors_15));
+    // x^4 + x^2 + 1 = (x^2 + x + 1)^2
+    assert!(!Gf2Poly::from_words(&[0b10101]).is_primitive(&factors_15));
+    // x^31 + x^3 + 1 is primitive, and 2^31-1 is prime.
+    assert!(Gf2Poly::from_words(&[0x80000009]).is_primitive(&[2147483647]));
+    assert!(!Gf2Poly::from_words(&[1]).is_primitive(&[]));
+    assert!(!Gf2Poly::zero().is_primitive(&[]));
+}
@@ -1,5 +1,13 @@
+use num_traits::Pow;
 use rand_core::RngCore;
-use simplerandom::RngJumpAhead;
+use simplerandom::biguint::BigUint;
+use simplerandom::bitcolumnmatrix::{BitColumnMatrix, BitColumnMatrixInt};
+use simplerandom::gf2poly::Gf2Poly;
+use simplerandom::maths;
+use simplerandom::modmatrix::ModMatrix;
+use simplerandom::{linear_jumpahead, LFSR113, SHR3};
+use simplerandom::{CombineAdd, CombineXor, Combined, Combined3, Period, RngJumpAhead};
+use simplerandom::{TauswortheComponent32, TauswortheComponent64, TauswortheError};
 
 #[test]
 fn test_kiss_million() {
@@ -135,3 +143,1015 @@ fn test_lfsr88_million() {
     assert_eq!(rng_ja, rng);
     assert_eq!(rng_ja.next_u32(), rng.next_u32());
 }
+
+#[test]
+fn test_pcg32_million() {
+    let mut rng = simplerandom::PCG32::with_stream(0x853c49e6748fea9b, 1);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 1333931300);
+
+    let mut rng_ja = simplerandom::PCG32::with_stream(0x853c49e6748fea9b, 1);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+}
+
+#[test]
+fn test_minstd_million() {
+    let mut rng = simplerandom::MINSTD::new(2051391225);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 4362586);
+
+    let mut rng_ja = simplerandom::MINSTD::new(2051391225);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+}
+
+#[test]
+fn test_mmix_million() {
+    let mut rng = simplerandom::MMIX::new(0x0123456789ABCDEF);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 361556631);
+
+    let mut rng_ja = simplerandom::MMIX::new(0x0123456789ABCDEF);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+}
+
+#[test]
+fn test_lcg_output_width() {
+    // java.util.Random: a 48-bit state, of which the upper 32 bits are output.
+    let mut rng = simplerandom::Lcg::<u64, 0x5DEECE66D, 11, { 1 << 48 }>::new(42 ^ 0x5DEECE66D);
+    assert_eq!(rng.next_u32() as i32, -1170105035);
+    assert_eq!(rng.next_u32() as i32, 234785527);
+    assert_eq!(rng.next_u32() as i32, -1360544799);
+    let mut rng_ja = simplerandom::Lcg::<u64, 0x5DEECE66D, 11, { 1 << 48 }>::new(42 ^ 0x5DEECE66D);
+    rng_ja.jumpahead(3);
+    assert_eq!(rng_ja, rng);
+
+    // A prime modulus wider than 32 bits: the lower 32 bits are output.
+    let mut rng = simplerandom::Lcg::<u64, 437799614237992725, 0, 2305843009213693951>::new(12345);
+    assert_eq!(rng.next_u32(), 2390224084);
+    assert_eq!(rng.next_u32(), 2260237616);
+    assert_eq!(rng.next_u32(), 2586068357);
+}
+
+#[test]
+fn test_mrg32k3a_million() {
+    let mut rng = simplerandom::MRG32k3a::new(1, 2, 3, 4, 5, 6);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 912349705);
+
+    let mut rng_ja = simplerandom::MRG32k3a::new(1, 2, 3, 4, 5, 6);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+}
+
+#[test]
+fn test_mrg32k3a_zero_seed() {
+    // An all-zero component, including one that reduces to zero modulo its modulus, is seeded
+    // with the default seed.
+    let seed = simplerandom::MRG32K3A_DEFAULT_SEED;
+    let mut rng_default = simplerandom::MRG32k3a::new(seed, seed, seed, seed, seed, seed);
+    let mut rng = simplerandom::MRG32k3a::new(0, 0, 0, 4294944443, 0, 4294944443);
+    for _ in 0..10 {
+        assert_eq!(rng.next_u32(), rng_default.next_u32());
+    }
+}
+
+#[test]
+fn test_mt19937_10000() {
+    // The C++ standard requires the 10000th consecutive invocation of a default-constructed
+    // std::mt19937 to produce this value.
+    let mut rng = simplerandom::MT19937::new(5489);
+    let mut k: u32 = 0;
+    for _ in 0..10000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 4123659995);
+
+    let mut rng_ja = simplerandom::MT19937::new(5489);
+    rng_ja.jumpahead(10000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+}
+
+#[test]
+fn test_mt19937_million() {
+    let mut rng = simplerandom::MT19937::new(2051391225);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 1162430522);
+
+    let mut rng_ja = simplerandom::MT19937::new(2051391225);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+}
+
+#[test]
+fn test_mt19937_jumpback() {
+    // The initial seeded state has 31 bits that aren't part of the linear recurrence, which
+    // can't be recovered by jumping back. So compare the states after one step.
+    let mut rng = simplerandom::MT19937::new(2051391225);
+    let mut rng_ja = rng.clone();
+    rng_ja.jumpahead(1_000_000_000_000_i64);
+    rng_ja.jumpahead(-1_000_000_000_000_i64);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+    assert_eq!(rng_ja, rng);
+}
+
+#[test]
+fn test_mt19937_64_10000() {
+    // The C++ standard requires the 10000th consecutive invocation of a default-constructed
+    // std::mt19937_64 to produce this value.
+    let mut rng = simplerandom::MT19937_64::new(5489);
+    let mut k: u64 = 0;
+    for _ in 0..10000 {
+        k = rng.next_u64();
+    }
+    assert_eq!(k, 9981545732273789042);
+
+    let mut rng_ja = simplerandom::MT19937_64::new(5489);
+    rng_ja.jumpahead(10000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u64(), rng.next_u64());
+}
+
+#[test]
+fn test_mt19937_64_million() {
+    let mut rng = simplerandom::MT19937_64::new(2051391225);
+    let mut k: u64 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u64();
+    }
+    assert_eq!(k, 13781628030667868155);
+
+    let mut rng_ja = simplerandom::MT19937_64::new(2051391225);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u64(), rng.next_u64());
+}
+
+#[test]
+fn test_well512a_million() {
+    let seeds: [u32; 16] = core::array::from_fn(|j| 0x12345678_u32.wrapping_mul(j as u32 + 1));
+    let mut rng = simplerandom::WELL512a::new(&seeds);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 512712670);
+
+    let mut rng_ja = simplerandom::WELL512a::new(&seeds);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+}
+
+#[test]
+fn test_well1024a_million() {
+    let seeds: [u32; 32] = core::array::from_fn(|j| 0x12345678_u32.wrapping_mul(j as u32 + 1));
+    let mut rng = simplerandom::WELL1024a::new(&seeds);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 2056285990);
+
+    let mut rng_ja = simplerandom::WELL1024a::new(&seeds);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+}
+
+#[test]
+fn test_well_zero_seed() {
+    let mut rng = simplerandom::WELL512a::new(&[0; 16]);
+    assert_eq!(rng, simplerandom::WELL512a::new(&[0xFFFFFFFF; 16]));
+    assert_ne!(rng.next_u32(), 0);
+
+    let mut rng = simplerandom::WELL1024a::new(&[0; 32]);
+    assert_eq!(rng, simplerandom::WELL1024a::new(&[0xFFFFFFFF; 32]));
+    assert_ne!(rng.next_u32(), 0);
+}
+
+#[test]
+fn test_well512a_jumpback() {
+    let rng = simplerandom::WELL512a::new(&[1; 16]);
+    let mut rng_ja = rng.clone();
+    rng_ja.jumpahead(1_000_000_000_000_000_000_i64);
+    rng_ja.jumpahead(-1_000_000_000_000_000_000_i64);
+    assert_eq!(rng_ja, rng);
+}
+
+#[test]
+fn test_splitmix64_million() {
+    let mut rng = simplerandom::SplitMix64::new(2051391225);
+    let mut k: u64 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u64();
+    }
+    assert_eq!(k, 6112998672677446474);
+
+    let mut rng_ja = simplerandom::SplitMix64::new(2051391225);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u64(), rng.next_u64());
+}
+
+#[test]
+fn test_philox4x32_kat() {
+    // Random123 known-answer tests for Philox4x32-10.
+    assert_eq!(
+        simplerandom::Philox4x32::block([0, 0, 0, 0], [0, 0]),
+        [0x6627e8d5, 0xe169c58d, 0xbc57ac4c, 0x9b00dbd8]
+    );
+    assert_eq!(
+        simplerandom::Philox4x32::block([0xffffffff; 4], [0xffffffff; 2]),
+        [0x408f276d, 0x41c83b0e, 0xa20bc7c6, 0x6d5451fd]
+    );
+    assert_eq!(
+        simplerandom::Philox4x32::block([0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344], [0xa4093822, 0x299f31d0]),
+        [0xd16cfe09, 0x94fdcceb, 0x5001e420, 0x24126ea1]
+    );
+}
+
+#[test]
+fn test_philox4x32_million() {
+    let mut rng = simplerandom::Philox4x32::new(1, 2);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 1846870056);
+    assert_eq!(rng.at(999_999), k);
+
+    let mut rng_ja = simplerandom::Philox4x32::new(1, 2);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+    rng_ja.jumpahead(-1_000_001);
+    assert_eq!(rng_ja, simplerandom::Philox4x32::new(1, 2));
+}
+
+#[test]
+fn test_threefry2x32_kat() {
+    // Random123 known-answer tests for Threefry2x32-20.
+    assert_eq!(simplerandom::Threefry2x32::block([0, 0], [0, 0]), [0x6b200159, 0x99ba4efe]);
+    assert_eq!(
+        simplerandom::Threefry2x32::block([0xffffffff; 2], [0xffffffff; 2]),
+        [0x1cb996fc, 0xbb002be7]
+    );
+    assert_eq!(
+        simplerandom::Threefry2x32::block([0x243f6a88, 0x85a308d3], [0x13198a2e, 0x03707344]),
+        [0xc4923a9c, 0x483df7a0]
+    );
+}
+
+#[test]
+fn test_threefry2x32_million() {
+    let mut rng = simplerandom::Threefry2x32::new(1, 2);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 687053718);
+    assert_eq!(rng.at(999_999), k);
+
+    let mut rng_ja = simplerandom::Threefry2x32::new(1, 2);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+    rng_ja.jumpahead(-1_000_001);
+    assert_eq!(rng_ja, simplerandom::Threefry2x32::new(1, 2));
+}
+
+#[test]
+fn test_mwc256_million() {
+    let mut rng = simplerandom::MWC256::from_u64_seed(2051391225);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 3192282550);
+
+    let mut rng_ja = simplerandom::MWC256::from_u64_seed(2051391225);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+    rng_ja.jumpahead(-1_000_001);
+    assert_eq!(rng_ja, simplerandom::MWC256::from_u64_seed(2051391225));
+}
+
+#[test]
+fn test_cmwc4096_million() {
+    let mut rng = simplerandom::CMWC4096::from_u64_seed(2051391225);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 3073808684);
+
+    let mut rng_ja = simplerandom::CMWC4096::from_u64_seed(2051391225);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+
+    // Jump back across the index wrap. Every short jump back is checked in test_mwc_lag_small.
+    let mut rng = simplerandom::CMWC4096::from_u64_seed(2051391225);
+    let jumps_back = [1, 16];
+    let mut expected = Vec::new();
+    for i in 0..=100 {
+        if jumps_back.contains(&(100 - i)) {
+            expected.push((100 - i, rng.clone()));
+        }
+        if i < 100 {
+            rng.next_u32();
+        }
+    }
+    for (k, state) in expected {
+        let mut rng_ja = rng.clone();
+        rng_ja.jumpahead(-k);
+        assert_eq!(rng_ja, state, "jumpahead(-{})", k);
+    }
+}
+
+/// Seed values as in Marsaglia's example code: the sum of a congruential generator and a 3-shift
+/// shift-register generator.
+fn marsaglia_mwc_seed<const R: usize>() -> [u32; R] {
+    let mut cng: u32 = 123456789;
+    let mut xs: u32 = 362436069;
+    let mut q = [0_u32; R];
+    for q_i in q.iter_mut() {
+        cng = cng.wrapping_mul(69069).wrapping_add(13579);
+        xs ^= xs << 13;
+        xs ^= xs >> 17;
+        xs ^= xs << 5;
+        *q_i = cng.wrapping_add(xs);
+    }
+    q
+}
+
+#[test]
+fn test_mwc256_reference() {
+    // Outputs of Marsaglia's MWC256 C code, with Q[] and c = 362436 seeded as in his example.
+    let mut rng = simplerandom::MWC256::new(&marsaglia_mwc_seed(), 362436);
+    assert_eq!(rng.next_u32(), 1250680588);
+    assert_eq!(rng.next_u32(), 3225646774);
+    assert_eq!(rng.next_u32(), 1660641574);
+    assert_eq!(rng.next_u32(), 2356613701);
+    let mut k: u32 = 0;
+    for _ in 4..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 422078614);
+}
+
+#[test]
+fn test_cmwc4096_reference() {
+    // Outputs of Marsaglia's CMWC4096 C code, with Q[] seeded as in his example. His initial
+    // carry 362436 is not less than A, so it is reduced here and the first output differs.
+    let mut rng = simplerandom::CMWC4096::new(&marsaglia_mwc_seed(), 362436);
+    assert_ne!(rng.next_u32(), 2283094535);
+    assert_eq!(rng.next_u32(), 105926520);
+    assert_eq!(rng.next_u32(), 1742842517);
+    assert_eq!(rng.next_u32(), 3062091101);
+    let mut k: u32 = 0;
+    for _ in 4..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 1223948788);
+
+    // With a carry less than A, all outputs match.
+    let mut rng = simplerandom::CMWC4096::new(&marsaglia_mwc_seed(), 1234);
+    assert_eq!(rng.next_u32(), 2283455737);
+    assert_eq!(rng.next_u32(), 105926520);
+}
+
+#[test]
+fn test_mwc_lag_small() {
+    // A small lag-3 generator, to exercise jumpahead across many periods of the index.
+    type Mwc3 = simplerandom::Mwc<1234567, 3, false>;
+    type Cmwc3 = simplerandom::Mwc<123456, 3, true>;
+    let mut rng = Mwc3::new(&[1, 2, 3], 4);
+    let mut rng_ja = rng.clone();
+    let mut rng_c = Cmwc3::new(&[1, 2, 3], 4);
+    let mut rng_c_ja = rng_c.clone();
+    for _ in 0..1000 {
+        rng.next_u32();
+        rng_c.next_u32();
+    }
+    rng_ja.jumpahead(1000);
+    rng_c_ja.jumpahead(1000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_c_ja, rng_c);
+    rng_c_ja.jumpahead(-1000);
+    assert_eq!(rng_c_ja, Cmwc3::new(&[1, 2, 3], 4));
+
+    // Jump back by every k up to 3 lags, compared to the stepped states.
+    let mut states = vec![Mwc3::new(&[1, 2, 3], 4)];
+    let mut states_c = vec![Cmwc3::new(&[1, 2, 3], 4)];
+    for _ in 0..9 {
+        let mut rng = states.last().unwrap().clone();
+        rng.next_u32();
+        states.push(rng);
+        let mut rng_c = states_c.last().unwrap().clone();
+        rng_c.next_u32();
+        states_c.push(rng_c);
+    }
+    for k in 1..=9_usize {
+        let mut rng_ja = states[9].clone();
+        rng_ja.jumpahead(-(k as i32));
+        assert_eq!(rng_ja, states[9 - k], "MWC jumpahead(-{})", k);
+        let mut rng_c_ja = states_c[9].clone();
+        rng_c_ja.jumpahead(-(k as i32));
+        assert_eq!(rng_c_ja, states_c[9 - k], "CMWC jumpahead(-{})", k);
+    }
+
+    // Round trip of forward then backward jumps.
+    for n in [1_i64, 2, 3, 4, 5, 6, 7, 8, 12, 13, 1000, 1_000_000_000_007] {
+        let mut rng_ja = Mwc3::new(&[1, 2, 3], 4);
+        rng_ja.jumpahead(n);
+        rng_ja.jumpahead(-n);
+        assert_eq!(rng_ja, Mwc3::new(&[1, 2, 3], 4), "MWC round trip {}", n);
+        let mut rng_c_ja = Cmwc3::new(&[1, 2, 3], 4);
+        rng_c_ja.jumpahead(n);
+        rng_c_ja.jumpahead(-n);
+        assert_eq!(rng_c_ja, Cmwc3::new(&[1, 2, 3], 4), "CMWC round trip {}", n);
+    }
+}
+
+#[test]
+fn test_mwc_sanitise() {
+    type Mwc3 = simplerandom::Mwc<1234567, 3, false>;
+    // The fixed points of MWC are avoided.
+    let mut rng = Mwc3::new(&[0, 0, 0], 0);
+    assert_eq!(rng, Mwc3::new(&[u32::MAX, u32::MAX, u32::MAX], 0));
+    assert_ne!(rng.next_u32(), u32::MAX);
+    let mut rng = Mwc3::new(&[u32::MAX, u32::MAX, u32::MAX], 1234566);
+    assert_eq!(rng, Mwc3::new(&[0, 0, 0], 1234566));
+    assert_ne!(rng.next_u32(), 0);
+    // Carry is reduced modulo the multiplier.
+    assert_eq!(Mwc3::new(&[1, 2, 3], 1234567 + 5), Mwc3::new(&[1, 2, 3], 5));
+    // CMWC base 2^32-1 values are reduced.
+    type Cmwc3 = simplerandom::Mwc<123456, 3, true>;
+    assert_eq!(Cmwc3::new(&[u32::MAX, 2, 3], 4), Cmwc3::new(&[0, 2, 3], 4));
+}
+
+#[test]
+fn test_ranlux_cpp_10000() {
+    // The 10000th output of the default-constructed C++ engines, from the C++ standard.
+    let seed = simplerandom::SWB_DEFAULT_SEED;
+    let mut rng = simplerandom::Ranlux24Base::new(seed);
+    let mut k = 0;
+    for _ in 0..10000 {
+        k = rng.next_word();
+    }
+    assert_eq!(k, 7937952);
+    let mut rng = simplerandom::Ranlux48Base::new(seed);
+    for _ in 0..10000 {
+        k = rng.next_word();
+    }
+    assert_eq!(k, 61839128582725);
+    let mut rng = simplerandom::Ranlux24::new(seed);
+    for _ in 0..10000 {
+        k = rng.next_word();
+    }
+    assert_eq!(k, 9901578);
+    let mut rng = simplerandom::Ranlux48::new(seed);
+    for _ in 0..10000 {
+        k = rng.next_word();
+    }
+    assert_eq!(k, 249142670248501);
+    // A seed of 0 is replaced by the default seed, as in C++.
+    assert_eq!(simplerandom::Ranlux48::new(0), simplerandom::Ranlux48::new(seed));
+}
+
+#[test]
+fn test_ranlux24_base_million() {
+    let mut rng = simplerandom::Ranlux24Base::new(12345);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 914334844);
+
+    // Each u32 takes two 24-bit words.
+    let mut rng_ja = simplerandom::Ranlux24Base::new(12345);
+    rng_ja.jumpahead(2_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+    rng_ja.jumpahead(-2_000_002);
+    assert_eq!(rng_ja, simplerandom::Ranlux24Base::new(12345));
+}
+
+#[test]
+fn test_ranlux48_base_million() {
+    let mut rng = simplerandom::Ranlux48Base::new(12345);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 1747062345);
+
+    let mut rng_ja = simplerandom::Ranlux48Base::new(12345);
+    rng_ja.jumpahead(1_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u32(), rng.next_u32());
+}
+
+#[test]
+fn test_ranlux24_jumpahead() {
+    let mut rng = simplerandom::Ranlux24::new(12345);
+    let mut k = 0;
+    for _ in 0..100_000 {
+        k = rng.next_word();
+    }
+    assert_eq!(k, 4704433);
+
+    let mut rng_ja = simplerandom::Ranlux24::new(12345);
+    rng_ja.jumpahead(100_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_word(), rng.next_word());
+    rng_ja.jumpahead(-100_001);
+    assert_eq!(rng_ja, simplerandom::Ranlux24::new(12345));
+}
+
+#[test]
+fn test_ranlux48_jumpahead() {
+    let mut rng = simplerandom::Ranlux48::new(12345);
+    let mut k = 0;
+    for _ in 0..100_000 {
+        k = rng.next_word();
+    }
+    assert_eq!(k, 14357987029076);
+
+    let mut rng_ja = simplerandom::Ranlux48::new(12345);
+    for n in [1_i64, 10, 11, 12, 99_966].iter() {
+        rng_ja.jumpahead(*n);
+    }
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_word(), rng.next_word());
+}
+
+#[test]
+fn test_cong16_million() {
+    let mut rng = simplerandom::Cong16::new(12345);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 1562403257);
+
+    // Each u32 takes two steps.
+    let mut rng_ja = simplerandom::Cong16::new(12345);
+    rng_ja.jumpahead(2_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u16(), rng.next_u16());
+}
+
+#[test]
+fn test_xorshift16_million() {
+    let mut rng = simplerandom::Xorshift16::new(12345);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 50151235);
+
+    // Each u32 takes two steps.
+    let mut rng_ja = simplerandom::Xorshift16::new(12345);
+    rng_ja.jumpahead(2_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u16(), rng.next_u16());
+}
+
+#[test]
+fn test_mwc8_million() {
+    let mut rng = simplerandom::MWC8::new(12345);
+    let mut k: u32 = 0;
+    for _ in 0..1_000_000 {
+        k = rng.next_u32();
+    }
+    assert_eq!(k, 2562103931);
+
+    // Each u32 takes four steps.
+    let mut rng_ja = simplerandom::MWC8::new(12345);
+    rng_ja.jumpahead(4_000_000);
+    assert_eq!(rng_ja, rng);
+    assert_eq!(rng_ja.next_u8(), rng.next_u8());
+}
+
+#[test]
+fn test_small_state_periods() {
+    // Every seed, including the "bad" ones, gives the full period.
+    for seed in [0_u16, 1, 0xFFFF].iter() {
+        let start = simplerandom::Xorshift16::new(*seed);
+        let mut rng = start.clone();
+        let mut period = 0_u32;
+        loop {
+            rng.next_u16();
+            period += 1;
+            if rng == start {
+                break;
+            }
+        }
+        assert_eq!(period, 65535);
+    }
+    // Both cycles of MWC8 (the quadratic residues and non-residues modulo 63743), and the seeds
+    // that are reduced or replaced.
+    for seed in [1_u16, 5, 0, 63743, 0xFFFF].iter() {
+        let start = simplerandom::MWC8::new(*seed);
+        let mut rng = start.clone();
+        let mut period = 0_u32;
+        loop {
+            rng.next_u8();
+            period += 1;
+            if rng == start {
+                break;
+            }
+        }
+        assert_eq!(period, 31871);
+    }
+    let start = simplerandom::Cong16::new(0);
+    let mut rng = start.clone();
+    let mut period = 0_u32;
+    loop {
+        rng.next_u16();
+        period += 1;
+        if rng == start {
+            break;
+        }
+    }
+    assert_eq!(period, 65536);
+}
+
+#[test]
+fn test_combined_kiss() {
+    // KISS, built from its parts.
+    type Kiss = Combined<Combined<simplerandom::MWC2, simplerandom::Cong, CombineXor>, simplerandom::SHR3, CombineAdd>;
+    let mut rng = simplerandom::KISS::new(2247183469, 99545079, 3269400377, 3950144837);
+    let mut rng_c: Kiss = (
+        (simplerandom::MWC2::new(2247183469, 99545079), simplerandom::Cong::new(3269400377)).into(),
+        simplerandom::SHR3::new(3950144837),
+    )
+        .into();
+    for _ in 0..1000 {
+        assert_eq!(rng_c.next_u32(), rng.next_u32());
+    }
+    rng.jumpahead(-1_000_000_000_i64);
+    rng_c.jumpahead(-1_000_000_000_i64);
+    assert_eq!(rng_c.next_u64(), rng.next_u64());
+    assert_eq!(Kiss::period(), simplerandom::KISS::period());
+}
+
+#[test]
+fn test_combined3() {
+    let mut rng: Combined3<simplerandom::LFSR113, simplerandom::MWC2, simplerandom::Cong, CombineXor> =
+        (simplerandom::LFSR113::new(1, 2, 3, 4), simplerandom::MWC2::new(5, 6), simplerandom::Cong::new(7)).into();
+    let mut lfsr113 = simplerandom::LFSR113::new(1, 2, 3, 4);
+    let mut mwc2 = simplerandom::MWC2::new(5, 6);
+    let mut cong = simplerandom::Cong::new(7);
+    rng.jumpahead(12345);
+    lfsr113.jumpahead(12345);
+    mwc2.jumpahead(12345);
+    cong.jumpahead(12345);
+    for _ in 0..100 {
+        assert_eq!(rng.next_u32(), lfsr113.next_u32() ^ mwc2.next_u32() ^ cong.next_u32());
+    }
+    assert_eq!(
+        Combined3::<simplerandom::LFSR113, simplerandom::MWC2, simplerandom::Cong, CombineXor>::period(),
+        simplerandom::LFSR113::period().lcm(&simplerandom::MWC2::period()).lcm(&simplerandom::Cong::period())
+    );
+}
+
+#[test]
+fn test_period() {
+    let one = BigUint::from_u64(1);
+    let mersenne = |k: u32| &BigUint::from_u64(2).pow(k) - &one;
+    assert_eq!(simplerandom::SHR3::period(), mersenne(32));
+    assert_eq!(simplerandom::Cong::period(), BigUint::from_u64(1 << 32));
+    assert_eq!(simplerandom::MINSTD::period(), BigUint::from_u64(2147483646));
+    assert_eq!(simplerandom::MWC2::period(), BigUint::from_u64(((36969 << 16) / 2 - 1) * ((18000 << 16) / 2 - 1)));
+    assert_eq!(simplerandom::LFSR88::period(), &(&mersenne(31) * &mersenne(29)) * &mersenne(28));
+    assert_eq!(
+        simplerandom::LFSR113::period(),
+        &(&(&mersenne(31) * &mersenne(29)) * &mersenne(28)) * &mersenne(25)
+    );
+    assert_eq!(simplerandom::MT19937::period().bits(), 19937);
+    assert_eq!(simplerandom::WELL1024a::period(), mersenne(1024));
+    // The MRG32k3a period is (m1^3-1)(m2^3-1)/2, about 2^191.
+    assert_eq!(simplerandom::MRG32k3a::period().bits(), 191);
+    assert_eq!(simplerandom::MWC8::period(), BigUint::from_u64(31871));
+}
+
+/// Check that the multiplicative order of a linear transition is exactly `n`. It starts from the
+/// steady state, since a Tausworthe component has bits that are not part of the recurrence.
+fn is_bit_matrix_order<T, const WIDTH: usize>(matrix: &BitColumnMatrix<T, WIDTH>, n: u128) -> bool
+where
+    T: BitColumnMatrixInt,
+{
+    let steady = matrix.clone().pow(WIDTH as u32);
+    let is_cycle = |e: u128| steady.dot(&matrix.clone().pow(e)) == steady;
+    is_cycle(n) && maths::factorise(n).iter().all(|&(q, _)| !is_cycle(n / q))
+}
+
+/// Check that the multiplicative order of a matrix modulo m is exactly `n`.
+fn is_mod_matrix_order<const WIDTH: usize>(matrix: &ModMatrix<u128, WIDTH>, n: u128) -> bool {
+    let one = ModMatrix::<u128, WIDTH>::one(matrix.modulus());
+    let is_cycle = |e: u128| matrix.clone().pow(e) == one;
+    is_cycle(n) && maths::factorise(n).iter().all(|&(q, _)| !is_cycle(n / q))
+}
+
+/// Check the Hull–Dobell conditions for an LCG to have full period `m`.
+// `is_multiple_of()` needs Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
+fn is_full_period_lcg(a: u128, c: u128, m: u128) -> bool {
+    let factors = maths::factorise(m);
+    factors.iter().all(|&(p, _)| (a - 1) % p == 0 && c % p != 0)
+        && (m % 4 != 0 || (a - 1) % 4 == 0)
+}
+
+#[test]
+fn test_cycle_len_lcg() {
+    let mut rng = simplerandom::Cong::new(12345678);
+    assert_eq!(rng.next_u32(), 12345678_u32.wrapping_mul(69069).wrapping_add(12345));
+    assert!(is_full_period_lcg(69069, 12345, 1 << 32));
+    assert_eq!(simplerandom::Cong::period(), BigUint::from_u128(1 << 32));
+    assert!(is_full_period_lcg(1103515245, 12345, 1 << 31));
+    assert_eq!(simplerandom::GLIBC::period(), BigUint::from_u128(1 << 31));
+    assert!(is_full_period_lcg(6364136223846793005, 1442695040888963407, 1 << 64));
+    assert_eq!(simplerandom::MMIX::period(), BigUint::from_u128(1 << 64));
+    assert!(is_full_period_lcg(25173, 13849, 1 << 16));
+    assert_eq!(simplerandom::Cong16::period(), BigUint::from_u128(1 << 16));
+    // PCG32's increment is always odd, so any stream has the full period.
+    assert!(is_full_period_lcg(6364136223846793005, 1, 1 << 64));
+    assert_eq!(simplerandom::PCG32::period(), BigUint::from_u128(1 << 64));
+    // SplitMix64's Weyl sequence is an LCG with multiplier 1.
+    assert!(is_full_period_lcg(1, 0x9E3779B97F4A7C15, 1 << 64));
+    assert_eq!(simplerandom::SplitMix64::period(), BigUint::from_u128(1 << 64));
+
+    // MINSTD has a prime modulus and no increment, so it needs a primitive root multiplier.
+    let mut rng = simplerandom::MINSTD::new(12345678);
+    assert_eq!(rng.next_u32() as u64, 12345678 * 16807 % 2147483647);
+    assert!(maths::is_prime(2147483647_u32));
+    assert!(maths::is_primitive_root(16807_u32, 2147483647));
+    assert_eq!(simplerandom::MINSTD::period(), BigUint::from_u64(2147483646));
+}
+
+#[test]
+fn test_cycle_len_mwc() {
+    // A multiply-with-carry generator with multiplier a and base b is equivalent to an LCG with
+    // multiplier b^-1 modulo m = a*b - 1. The period is the multiplicative order of b, which is
+    // (m - 1)/2 when m is a safe prime and b is a quadratic residue.
+    let mwc_cycle_len = |a: u64, b: u64| {
+        let m = a * b - 1;
+        assert!(maths::is_prime(m));
+        assert_eq!(maths::multiplicative_order(a, m), maths::multiplicative_order(b, m));
+        maths::multiplicative_order(b, m).unwrap()
+    };
+    let upper = mwc_cycle_len(36969, 1 << 16);
+    let lower = mwc_cycle_len(18000, 1 << 16);
+    assert_eq!(upper, ((36969 << 16) - 1) / 2);
+    assert_eq!(lower, ((18000 << 16) - 1) / 2);
+    let period = BigUint::from_u64(upper).lcm(&BigUint::from_u64(lower));
+    assert_eq!(simplerandom::MWC2::period(), period);
+    assert_eq!(simplerandom::MWC1::period(), period);
+
+    let mwc64 = mwc_cycle_len(698769069, 1 << 32);
+    assert_eq!(mwc64, ((698769069 << 32) - 1) / 2);
+    assert_eq!(simplerandom::MWC64::period(), BigUint::from_u64(mwc64));
+
+    let mwc8 = mwc_cycle_len(249, 1 << 8);
+    assert_eq!(mwc8, 31871);
+    assert_eq!(simplerandom::MWC8::period(), BigUint::from_u64(mwc8));
+}
+
+#[test]
+fn test_cycle_len_xorshift() {
+    let shr3 = BitColumnMatrix::<u32, 32>::from_linear_fn(|x| SHR3::new(x).next_u32());
+    assert!(is_bit_matrix_order(&shr3, 0xFFFFFFFF));
+    assert_eq!(SHR3::period(), BigUint::from_u64(0xFFFFFFFF));
+
+    let xorshift16 =
+        BitColumnMatrix::<u16, 16>::from_linear_fn(|x| simplerandom::Xorshift16::new(x).next_u16());
+    assert!(is_bit_matrix_order(&xorshift16, 0xFFFF));
+    assert_eq!(simplerandom::Xorshift16::period(), BigUint::from_u64(0xFFFF));
+}
+
+#[test]
+fn test_cycle_len_lfsr() {
+    let period = |parameters: &[(u32, u32, u32)]| {
+        parameters.iter().fold(BigUint::from_u64(1), |period, &(k, q, s)| {
+            let z = TauswortheComponent32::new(k, q, s, 0xFFFFFFFF).unwrap();
+            assert!(is_bit_matrix_order(z.matrix(), z.cycle_len()));
+            assert_eq!(z.cycle_len(), (1 << k) - 1);
+            period.lcm(&BigUint::from_u128(z.cycle_len()))
+        })
+    };
+    assert_eq!(simplerandom::LFSR88::period(), period(&[(31, 13, 12), (29, 2, 4), (28, 3, 17)]));
+    assert_eq!(LFSR113::period(), period(&[(31, 6, 18), (29, 2, 2), (28, 13, 7), (25, 3, 13)]));
+}
+
+#[test]
+fn test_cycle_len_mrg32k3a() {
+    // Each component is a lag-3 recurrence modulo a prime m, with full period m^3 - 1 when its
+    // characteristic polynomial is primitive, which is when its companion matrix has that order.
+    let (m1, m2) = (4294967087_u128, 4294944443_u128);
+    let (a12, a13n, a21, a23n) = (1403580, 810728, 527612, 1370589);
+    let mut rng = simplerandom::MRG32k3a::new(1, 2, 3, 4, 5, 6);
+    let p1 = (a12 * 2 + (m1 - a13n)) % m1;
+    let p2 = (a21 * 6 + (m2 - a23n) * 4) % m2;
+    assert_eq!(rng.next_u32() as u128, if p1 > p2 { p1 - p2 } else { p1 + m1 - p2 });
+
+    assert!(maths::is_prime(m1) && maths::is_prime(m2));
+    let s1 = ModMatrix::new(&[[0, 1, 0], [0, 0, 1], [m1 - a13n, a12, 0]], m1);
+    let s2 = ModMatrix::new(&[[0, 1, 0], [0, 0, 1], [m2 - a23n, 0, a21]], m2);
+    assert!(is_mod_matrix_order(&s1, m1.pow(3) - 1));
+    assert!(is_mod_matrix_order(&s2, m2.pow(3) - 1));
+    assert_eq!(
+        simplerandom::MRG32k3a::period(),
+        BigUint::from_u128(m1.pow(3) - 1).lcm(&BigUint::from_u128(m2.pow(3) - 1))
+    );
+}
+
+#[test]
+fn test_tausworthe_lfsr113() {
+    let seed = |s: u32| s ^ (s << 16);
+    let mut z1 = TauswortheComponent32::new(31, 6, 18, seed(1)).unwrap();
+    let mut z2 = TauswortheComponent32::new(29, 2, 2, seed(2)).unwrap();
+    let mut z3 = TauswortheComponent32::new(28, 13, 7, seed(3)).unwrap();
+    let mut z4 = TauswortheComponent32::new(25, 3, 13, seed(4)).unwrap();
+    let mut rng = simplerandom::LFSR113::new(1, 2, 3, 4);
+    for _ in 0..10_000 {
+        assert_eq!(z1.next_z() ^ z2.next_z() ^ z3.next_z() ^ z4.next_z(), rng.next_u32());
+    }
+    for n in [1_i64, 1_000_000_007, -12345, 1 << 40] {
+        z1.jumpahead(n);
+        z2.jumpahead(n);
+        z3.jumpahead(n);
+        z4.jumpahead(n);
+        rng.jumpahead(n);
+        assert_eq!(z1.next_z() ^ z2.next_z() ^ z3.next_z() ^ z4.next_z(), rng.next_u32());
+    }
+    assert_eq!(z4.parameters(), (25, 3, 13));
+    assert_eq!(z4.cycle_len(), (1 << 25) - 1);
+}
+
+#[test]
+fn test_tausworthe_period() {
+    // Step through a whole cycle of a small component. Only the top k bits are part of the
+    // recurrence.
+    let mut z = TauswortheComponent32::new(17, 3, 5, 0xFFFFFFFF).unwrap();
+    let mask = 0xFFFFFFFF_u32 << (32 - 17);
+    let start = z.next_z() & mask;
+    let mut n = 0;
+    loop {
+        n += 1;
+        if z.next_z() & mask == start {
+            break;
+        }
+    }
+    assert_eq!(n, z.cycle_len());
+    assert_eq!(n, (1 << 17) - 1);
+}
+
+#[test]
+fn test_tausworthe_64() {
+    // First component of L'Ecuyer's LFSR258.
+    let mut z = TauswortheComponent64::new(63, 1, 10, 123456789123456789).unwrap();
+    let mut z_jump = z.clone();
+    for _ in 0..2 {
+        z.next_z();
+    }
+    assert_eq!(z.next_z(), 16948197432601990894);
+    z_jump.jumpahead(3);
+    assert_eq!(z_jump.state(), z.state());
+    for _ in 0..1000 {
+        z.next_z();
+    }
+    z_jump.jumpahead(1000);
+    assert_eq!(z_jump, z);
+    z_jump.jumpahead(-1003);
+    assert_eq!(z_jump.next_z(), TauswortheComponent64::new(63, 1, 10, 123456789123456789).unwrap().next_z());
+}
+
+#[test]
+fn test_tausworthe_invalid() {
+    // 2q must be less than k.
+    assert_eq!(TauswortheComponent32::new(31, 16, 12, 1), Err(TauswortheError::InvalidParameters));
+    // k must fit the word size.
+    assert_eq!(TauswortheComponent32::new(33, 6, 12, 1), Err(TauswortheError::InvalidParameters));
+    // s must be in 1..=k-q.
+    assert_eq!(TauswortheComponent32::new(31, 6, 0, 1), Err(TauswortheError::InvalidParameters));
+    assert_eq!(TauswortheComponent32::new(31, 6, 26, 1), Err(TauswortheError::InvalidParameters));
+    // x^10 + x^3 + 1 is primitive, but s = 3 is a factor of 2^10-1.
+    assert!(TauswortheComponent32::new(10, 3, 1, 1).is_ok());
+    assert_eq!(TauswortheComponent32::new(10, 3, 3, 1), Err(TauswortheError::NotPrimitive));
+    // x^8 + x^3 + 1 is not primitive.
+    assert_eq!(TauswortheComponent32::new(8, 3, 1, 1), Err(TauswortheError::NotPrimitive));
+}
+
+#[test]
+fn test_linear_jumpahead_shr3() {
+    let matrix = BitColumnMatrix::<u32, 32>::from_linear_fn(|z| SHR3::new(z).next_u32());
+    let char_poly = matrix.char_poly();
+    for n in [0_i64, 1, 2, 31, 1_000_000, 0xFFFFFFFF, 0x1_0000_0000, -1, -2, -1_000_000_007] {
+        let mut s = SHR3::new(0x12345678);
+        let t = linear_jumpahead(&s, n, &char_poly);
+        s.jumpahead(n);
+        assert_eq!(s, t);
+    }
+}
+
+#[test]
+fn test_linear_jumpahead_lfsr113() {
+    // The characteristic polynomial of the combined state is the product of the components'.
+    let char_poly = [(31, 6, 18), (29, 2, 2), (28, 13, 7), (25, 3, 13)]
+        .iter()
+        .map(|(k, q, s)| TauswortheComponent32::new(*k, *q, *s, 1).unwrap().matrix().char_poly())
+        .fold(Gf2Poly::from_words(&[1]), |a, b| a.mul(&b));
+    assert_eq!(char_poly.degree(), Some(128));
+    for n in [0_u64, 1, 2, 1_000_000, 1_000_000_000_000_000_000] {
+        let mut s = LFSR113::new(1, 2, 3, 4);
+        let t = linear_jumpahead(&s, n, &char_poly);
+        s.jumpahead(n);
+        assert_eq!(s, t);
+        let mut t = t;
+        assert_eq!(s.next_u32(), t.next_u32());
+    }
+}
+
+#[test]
+fn test_from_u64_seed() {
+    // The documented seed expansion: upper 32 bits of successive SplitMix64 outputs.
+    let seed = 0x0123456789ABCDEF_u64;
+    let seeds: Vec<u32> = {
+        let mut sm = simplerandom::SplitMix64::new(seed);
+        (0..32).map(|_| (sm.next_u64() >> 32) as u32).collect()
+    };
+    assert_eq!(simplerandom::MWC1::from_u64_seed(seed), simplerandom::MWC1::new(seeds[0], seeds[1]));
+    assert_eq!(simplerandom::MWC2::from_u64_seed(seed), simplerandom::MWC2::new(seeds[0], seeds[1]));
+    assert_eq!(simplerandom::MWC64::from_u64_seed(seed), simplerandom::MWC64::new(seeds[0], seeds[1]));
+    assert_eq!(
+        simplerandom::KISS::from_u64_seed(seed),
+        simplerandom::KISS::new(seeds[0], seeds[1], seeds[2], seeds[3])
+    );
+    assert_eq!(
+        simplerandom::KISS2::from_u64_seed(seed),
+        simplerandom::KISS2::new(seeds[0], seeds[1], seeds[2], seeds[3])
+    );
+    assert_eq!(
+        simplerandom::LFSR88::from_u64_seed(seed),
+        simplerandom::LFSR88::new(seeds[0], seeds[1], seeds[2])
+    );
+    assert_eq!(
+        simplerandom::LFSR113::from_u64_seed(seed),
+        simplerandom::LFSR113::new(seeds[0], seeds[1], seeds[2], seeds[3])
+    );
+    assert_eq!(
+        simplerandom::MRG32k3a::from_u64_seed(seed),
+        simplerandom::MRG32k3a::new(seeds[0], seeds[1], seeds[2], seeds[3], seeds[4], seeds[5])
+    );
+    assert_eq!(
+        simplerandom::WELL512a::from_u64_seed(seed),
+        simplerandom::WELL512a::new(&core::array::from_fn(|i| seeds[i]))
+    );
+    assert_eq!(
+        simplerandom::WELL1024a::from_u64_seed(seed),
+        simplerandom::WELL1024a::new(&core::array::from_fn(|i| seeds[i]))
+    );
+
+    // 64-bit seed values take the whole SplitMix64 output.
+    let mut sm = simplerandom::SplitMix64::new(seed);
+    let (seed1, stream) = (sm.next_u64(), sm.next_u64());
+    assert_eq!(simplerandom::PCG32::from_u64_seed(seed), simplerandom::PCG32::with_stream(seed1, stream));
+
+    // Known values, for checking other implementations of the seed expansion.
+    assert_eq!(
+        simplerandom::KISS::from_u64_seed(1),
+        simplerandom::KISS::new(2433363436, 3203108257, 4170425070, 1908508304)
+    );
+    assert_eq!(
+        simplerandom::MWC1::from_u64_seed(1),
+        simplerandom::MWC1::new(2433363436, 3203108257)
+    );
+}
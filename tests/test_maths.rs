@@ -314,6 +314,28 @@ fn test_modulo() {
 
     let result = maths::modulo(1_000_000_000_000_000_000_i64, 3442199977_u32);
     assert_eq!(result, 3019173309_u32);
+
+    // A modulus of 0 is the natural wrapping of the modulus type.
+    let result = maths::modulo(217_u8, 0_u8);
+    assert_eq!(result, 217_u8);
+
+    let result = maths::modulo(3_000_000_001_u32, 0_u8);
+    assert_eq!(result, 0x01_u8);
+
+    let result = maths::modulo(-1_i32, 0_u8);
+    assert_eq!(result, 0xFF_u8);
+
+    let result = maths::modulo(-1_000_000_000_i32, 0_u16);
+    assert_eq!(result, 0x3600_u16);
+
+    let result = maths::modulo(-256_i32, 0_u8);
+    assert_eq!(result, 0_u8);
+
+    let result = maths::modulo(i128::MIN, 0_u128);
+    assert_eq!(result, 1_u128 << 127);
+
+    let result = maths::modulo(-3_i64, 0_u128);
+    assert_eq!(result, u128::MAX - 2);
 }
 
 #[test]
@@ -376,3 +398,221 @@ fn test_wrapping_geom_series() {
     let result = maths::wrapping_geom_series(69069_u32, 1_000_000_000_000_000_000_u64);
     assert_eq!(result, 629932032_u32);
 }
+
+#[test]
+fn test_add_mod() {
+    let result = maths::add_mod(3_u8, 4, 7);
+    assert_eq!(result, 0_u8);
+
+    let result = maths::add_mod(250_u8, 249, 251);
+    assert_eq!(result, 248_u8);
+
+    let result = maths::add_mod(0xFFFFFFF0_u32, 0xFFFFFFF1, 0xFFFFFFFB);
+    assert_eq!(result, 0xFFFFFFE6_u32);
+
+    let result = maths::add_mod(1_u64, 2, 0xFFFFFFFFFFFFFFC5);
+    assert_eq!(result, 3_u64);
+}
+
+#[test]
+fn test_geom_series_mod() {
+    let result = maths::geom_series_mod(3_u32, 0_u32, 7);
+    assert_eq!(result, 0_u32);
+
+    let result = maths::geom_series_mod(5_u32, 1_u32, 7);
+    assert_eq!(result, 1_u32);
+
+    let result = maths::geom_series_mod(21345_u32, 12345_u16, 0xFFFFFFFB);
+    assert_eq!(result, 1340557353_u32);
+
+    let result = maths::geom_series_mod(0xFFFFFFFD_u32, 123456789_u32, 0xFFFFFFFB);
+    assert_eq!(result, 3862799564_u32);
+
+    let result = maths::geom_series_mod(69069_u64, 1_000_000_000_000_000_000_u64, (1 << 61) - 1);
+    assert_eq!(result, 822002943924637543_u64);
+
+    let result = maths::geom_series_mod(
+        0xDC28D76FFD9338E9_u64,
+        0x732E73C316878E24_u64,
+        0xFFFFFFFFFFFFFFC5,
+    );
+    assert_eq!(result, 6485925692379971515_u64);
+}
+
+#[test]
+fn test_is_prime() {
+    let small_primes: Vec<u32> = (0..200_u32)
+        .filter(|&n| n >= 2 && (2..n).all(|d| n % d != 0))
+        .collect();
+    for n in 0..200_u32 {
+        assert_eq!(maths::is_prime(n), small_primes.contains(&n), "n = {}", n);
+    }
+    assert!(maths::is_prime(251_u8));
+    assert!(!maths::is_prime(255_u8));
+    assert!(maths::is_prime(65521_u16));
+    assert!(maths::is_prime(2147483647_u32));
+    assert!(maths::is_prime(4294967291_u32));
+    assert!(!maths::is_prime(4294967295_u32));
+    // Strong pseudoprimes to all the prime bases up to 7, 31 and 37 respectively.
+    assert!(!maths::is_prime(3215031751_u64));
+    assert!(!maths::is_prime(3825123056546413051_u64));
+    assert!(!maths::is_prime(318665857834031151167461_u128));
+    // Carmichael number.
+    assert!(!maths::is_prime(561_u64));
+    assert!(maths::is_prime(0xFFFFFFFFFFFFFFC5_u64));
+    assert!(!maths::is_prime(0xFFFFFFFFFFFFFFFF_u64));
+    assert!(maths::is_prime((1_u128 << 89) - 1));
+    assert!(maths::is_prime((1_u128 << 127) - 1));
+    assert!(!maths::is_prime((1_u128 << 67) - 1));
+}
+
+#[test]
+fn test_pollard_rho() {
+    let factor = maths::pollard_rho(4294967297_u64);
+    assert!(factor == 641 || factor == 6700417);
+    let factor = maths::pollard_rho(1000000016000000063_u64);
+    assert!(factor == 1000000007 || factor == 1000000009);
+    let factor = maths::pollard_rho((1_u128 << 67) - 1);
+    assert!(factor == 193707721 || factor == 761838257287);
+}
+
+#[test]
+fn test_factorise() {
+    assert_eq!(maths::factorise(0_u32), vec![]);
+    assert_eq!(maths::factorise(1_u32), vec![]);
+    assert_eq!(maths::factorise(255_u8), vec![(3, 1), (5, 1), (17, 1)]);
+    assert_eq!(maths::factorise(2147483646_u32), vec![(2, 1), (3, 2), (7, 1), (11, 1), (31, 1), (151, 1), (331, 1)]);
+    assert_eq!(maths::factorise(0xFFFFFFFFFFFFFFFF_u64), vec![(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]);
+    assert_eq!(maths::factorise(43_u64 * 43 * 47 * 1000003 * 1000003), vec![(43, 2), (47, 1), (1000003, 2)]);
+    assert_eq!(
+        maths::factorise(4294967087_u128.pow(3) - 1),
+        vec![(2, 1), (2147483543, 1), (18446742282708232657, 1)]
+    );
+}
+
+#[test]
+fn test_multiplicative_order() {
+    assert_eq!(maths::multiplicative_order(2_u32, 7), Some(3));
+    assert_eq!(maths::multiplicative_order(3_u32, 7), Some(6));
+    assert_eq!(maths::multiplicative_order(1_u32, 7), Some(1));
+    assert_eq!(maths::multiplicative_order(7_u32, 7), None);
+    assert_eq!(maths::multiplicative_order(3_u32, 1), Some(1));
+    // Composite modulus, where the order divides λ(m) rather than φ(m).
+    assert_eq!(maths::multiplicative_order(3_u32, 1 << 16), Some(1 << 14));
+    assert_eq!(maths::multiplicative_order(5_u32, 63), Some(6));
+    assert_eq!(maths::multiplicative_order(2_u64, 0xFFFFFFFFFFFFFFC5), Some(0xFFFFFFFFFFFFFFC4));
+    assert_eq!(maths::multiplicative_order(2_u128, (1 << 89) - 1), Some(89));
+}
+
+#[test]
+fn test_is_primitive_root() {
+    let roots: Vec<u32> = (1..23).filter(|&g| maths::is_primitive_root(g, 23)).collect();
+    assert_eq!(roots, vec![5, 7, 10, 11, 14, 15, 17, 19, 20, 21]);
+    assert!(maths::is_primitive_root(3_u32, 2 * 5 * 5));
+    assert!(!maths::is_primitive_root(3_u32, 8));
+    assert!(maths::is_primitive_root(48271_u32, 2147483647));
+    assert!(!maths::is_primitive_root(69069_u64, 1 << 32));
+    assert!(!maths::is_primitive_root(6_u32, 9));
+    assert!(maths::is_primitive_root(3_u32, 1));
+    assert!(!maths::is_primitive_root(3_u32, 0));
+}
+
+#[test]
+fn test_gcd() {
+    assert_eq!(maths::gcd(0_u32, 0), 0);
+    assert_eq!(maths::gcd(0_u32, 5), 5);
+    assert_eq!(maths::gcd(5_u32, 0), 5);
+    assert_eq!(maths::gcd(1071_u32, 462), 21);
+    assert_eq!(maths::gcd(462_u32, 1071), 21);
+    assert_eq!(maths::gcd(255_u8, 170), 85);
+    // Consecutive Fibonacci numbers, the worst case for Euclid's algorithm.
+    assert_eq!(maths::gcd(12200160415121876738_u64, 7540113804746346429), 1);
+    assert_eq!(maths::gcd(1_u128 << 100, 3 << 90), 1 << 90);
+}
+
+#[test]
+fn test_extended_gcd() {
+    // Exhaustively for u8, where the coefficients are closest to the limits of i8.
+    for a in 0..=255_u8 {
+        for b in 0..=255_u8 {
+            let (g, x, y) = maths::extended_gcd(a, b);
+            assert_eq!(g, maths::gcd(a, b));
+            assert_eq!(a as i32 * x as i32 + b as i32 * y as i32, g as i32, "a = {}, b = {}", a, b);
+        }
+    }
+    assert_eq!(maths::extended_gcd(240_u32, 46), (2, -9, 47));
+    assert_eq!(maths::extended_gcd(0xFFFFFFFF_u32, 0xFFFFFFFE), (1, 1, -1));
+    let (a, b) = (12200160415121876738_u64, 7540113804746346429_u64);
+    let (g, x, y) = maths::extended_gcd(a, b);
+    assert_eq!(g, 1);
+    assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, 1);
+}
+
+#[test]
+fn test_mod_inverse() {
+    for m in 1..=255_u8 {
+        for a in 0..m {
+            let result = maths::mod_inverse(a, m);
+            if maths::gcd(a, m) == 1 {
+                let x = result.unwrap();
+                assert!(x < m);
+                assert_eq!(maths::mul_mod(a, x, m), 1 % m);
+            } else {
+                assert_eq!(result, None);
+            }
+        }
+    }
+    assert_eq!(maths::mod_inverse(16807_u32, 2147483647), Some(1407677000));
+    assert_eq!(maths::mod_inverse(2147483647 + 16807_u32, 2147483647), Some(1407677000));
+    // An MWC multiplier's inverse, modulo the equivalent LCG's modulus, is the base.
+    assert_eq!(maths::mod_inverse(36969_u32, (36969 << 16) - 1), Some(1 << 16));
+    assert_eq!(maths::mod_inverse(2_u64, 0xFFFFFFFFFFFFFFC5), Some(0x7FFFFFFFFFFFFFE3));
+    assert_eq!(maths::mod_inverse(0xFFFFFFFFFFFFFFFE_u64, 0xFFFFFFFFFFFFFFFF), Some(0xFFFFFFFFFFFFFFFE));
+    let m = (1_u128 << 127) - 1;
+    let x = maths::mod_inverse(3_u128, m).unwrap();
+    assert_eq!(maths::mul_mod(3, x, m), 1);
+    // No inverse for a zero modulus.
+    assert_eq!(maths::mod_inverse(3_u32, 0), None);
+}
+
+#[test]
+fn test_wrapping_inverse() {
+    for a in 0..=255_u8 {
+        match maths::wrapping_inverse(a) {
+            Some(x) => assert_eq!(a.wrapping_mul(x), 1),
+            None => assert_eq!(a % 2, 0),
+        }
+    }
+    assert_eq!(maths::wrapping_inverse(25173_u16), Some(35581));
+    assert_eq!(maths::wrapping_inverse(69069_u32), Some(2783094533));
+    assert_eq!(maths::wrapping_inverse(6364136223846793005_u64), Some(13877824140714322085));
+    assert_eq!(maths::wrapping_inverse(0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF_u128), Some(0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF));
+    assert_eq!(maths::wrapping_inverse(0_u32), None);
+    assert_eq!(maths::wrapping_inverse(1_u64 << 63), None);
+}
+
+#[test]
+fn test_crt_combine() {
+    // Exhaustively for small moduli, against a search.
+    for m1 in 1..=12_u32 {
+        for m2 in 1..=12_u32 {
+            let m = m1 * m2 / maths::gcd(m1, m2);
+            for a1 in 0..m1 {
+                for a2 in 0..m2 {
+                    let expected = (0..m).find(|x| x % m1 == a1 && x % m2 == a2).map(|x| (x, m));
+                    assert_eq!(maths::crt_combine(a1, m1, a2, m2), expected);
+                }
+            }
+        }
+    }
+    // Distance into the period of the two MWC2 components.
+    let (m1, m2) = (1211400191_u64, 589823999_u64);
+    let n = 123456789012345678_u64;
+    assert_eq!(maths::crt_combine(n % m1, m1, n % m2, m2), Some((n % (m1 * m2), m1 * m2)));
+    // The least common multiple overflows.
+    assert_eq!(maths::crt_combine(1_u32, 65537, 2, 65539), None);
+    assert_eq!(maths::crt_combine(1_u32, 1 << 31, 0, 3), None);
+    // A zero modulus.
+    assert_eq!(maths::crt_combine(1_u32, 0, 2, 5), None);
+    assert_eq!(maths::crt_combine(1_u32, 5, 2, 0), None);
+}
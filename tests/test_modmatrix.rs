@@ -0,0 +1,78 @@
+use ::simplerandom::modmatrix::ModMatrix;
+
+use num_traits::Pow;
+
+type ModMatrix3 = ModMatrix<u32, 3>;
+
+const M1: u32 = 4294967087;
+const M2: u32 = 4294944443;
+
+// MRG32k3a component transition matrices.
+const A1: [[u32; 3]; 3] = [[0, 1, 0], [0, 0, 1], [M1 - 810728, 1403580, 0]];
+const A2: [[u32; 3]; 3] = [[0, 1, 0], [0, 0, 1], [M2 - 1370589, 0, 527612]];
+
+#[test]
+fn test_one() {
+    let one = ModMatrix3::one(M1);
+    let v = [1_u32, 0xFFFF0000, M1 - 1];
+    assert_eq!(one.dot_vec(&v), v);
+
+    let a1 = ModMatrix3::new(&A1, M1);
+    assert_eq!(&a1 * &one, a1);
+    assert_eq!(&one * &a1, a1);
+}
+
+#[test]
+fn test_zero() {
+    let zero = ModMatrix3::zero(M1);
+    let v = [1_u32, 0xFFFF0000, M1 - 1];
+    assert_eq!(zero.dot_vec(&v), [0, 0, 0]);
+}
+
+#[test]
+fn test_dot_vec() {
+    // One step of the MRG32k3a first component.
+    let a1 = ModMatrix3::new(&A1, M1);
+    let result = a1.dot_vec(&[12345, 12345, 12345]);
+    assert_eq!(result, [12345, 12345, 3023790853]);
+}
+
+#[test]
+fn test_pow() {
+    let a1 = ModMatrix3::new(&A1, M1);
+    assert_eq!(a1.clone().pow(0_u32), ModMatrix3::one(M1));
+    assert_eq!(a1.clone().pow(1_u32), a1);
+    assert_eq!(a1.clone().pow(3_u32), &(&a1 * &a1) * &a1);
+
+    // Period of the MRG32k3a components is m^3 - 1.
+    let cycle_len = (M1 as u128).pow(3) - 1;
+    assert_eq!(a1.clone().pow(cycle_len), ModMatrix3::one(M1));
+    let a2 = ModMatrix3::new(&A2, M2);
+    let cycle_len = (M2 as u128).pow(3) - 1;
+    assert_eq!(a2.clone().pow(cycle_len), ModMatrix3::one(M2));
+}
+
+#[test]
+fn test_pow_rngstreams() {
+    // Stream and substream jump matrices, from L'Ecuyer's RngStreams.c
+    const A1P76: [[u32; 3]; 3] = [
+        [82758667, 1871391091, 4127413238],
+        [3672831523, 69195019, 1871391091],
+        [3672091415, 3528743235, 69195019],
+    ];
+    const A1P127: [[u32; 3]; 3] = [
+        [2427906178, 3580155704, 949770784],
+        [226153695, 1230515664, 3580155704],
+        [1988835001, 986791581, 1230515664],
+    ];
+    const A2P127: [[u32; 3]; 3] = [
+        [1464411153, 277697599, 1610723613],
+        [32183930, 1464411153, 1022607788],
+        [2824425944, 32183930, 2093834863],
+    ];
+    let a1 = ModMatrix3::new(&A1, M1);
+    let a2 = ModMatrix3::new(&A2, M2);
+    assert_eq!(a1.clone().pow(1_u128 << 76), ModMatrix3::new(&A1P76, M1));
+    assert_eq!(a1.pow(1_u128 << 127), ModMatrix3::new(&A1P127, M1));
+    assert_eq!(a2.pow(1_u128 << 127), ModMatrix3::new(&A2P127, M2));
+}
@@ -0,0 +1,148 @@
+use ::simplerandom::stats::{self, Reversed};
+use ::simplerandom::{Cong, KISS, LFSR113, LFSR88, Lcg, MWC1, SHR3};
+
+fn assert_close(a: f64, b: f64) {
+    assert!(
+        (a - b).abs() <= 1e-9 * b.abs().max(1e-300),
+        "{} != {}",
+        a,
+        b
+    );
+}
+
+fn failure_names(report: &stats::Report) -> Vec<&'static str> {
+    report.failures().iter().map(|r| r.name).collect()
+}
+
+#[test]
+fn test_p_values() {
+    assert_close(stats::chi_square_p_value(3.841459, 1), 0.0499999946531958);
+    assert_close(stats::chi_square_p_value(18.307038, 10), 0.0500000008247323);
+    assert_close(stats::chi_square_p_value(4200.0, 4095), 0.123468968470609);
+    assert_close(stats::normal_p_value(1.959964), 0.0499999981928848);
+    assert_close(stats::normal_p_value(-1.959964), 0.0499999981928848);
+    assert_close(stats::poisson_p_value(10, 2.0), 0.0000929961500345276);
+    assert_close(stats::poisson_p_value(0, 20.0), 0.00000000412230724487712);
+    assert_eq!(stats::poisson_p_value(2, 2.0), 1.0);
+}
+
+#[test]
+fn test_good_generators_pass() {
+    let report = stats::small_battery(&mut KISS::new(1, 2, 3, 4));
+    assert_eq!(report.results().len(), 10);
+    assert!(report.passed(), "{}", report);
+    let report = stats::small_battery(&mut Reversed(KISS::new(1, 2, 3, 4)));
+    assert!(report.passed(), "{}", report);
+    let report = stats::small_battery(&mut MWC1::new(1, 2));
+    assert!(report.passed(), "{}", report);
+}
+
+#[test]
+fn test_cong_low_bits() {
+    // The most significant bits of Cong are fine.
+    let report = stats::small_battery(&mut Cong::new(1));
+    assert!(report.passed(), "{}", report);
+
+    // But its low bits have short periods: bit i has period 2^(i+1).
+    let report = stats::small_battery(&mut Reversed(Cong::new(1)));
+    assert_eq!(report.p_value("serial pairs"), Some(0.0));
+    assert_eq!(report.p_value("linear complexity"), Some(0.0));
+    assert_eq!(
+        failure_names(&report),
+        [
+            "serial pairs",
+            "collision",
+            "gap",
+            "poker",
+            "max-of-t",
+            "linear complexity"
+        ]
+    );
+}
+
+#[test]
+fn test_shr3_rank() {
+    // 32 successive outputs of SHR3 are always linearly independent, and its bits satisfy a
+    // linear recurrence of degree 32.
+    let report = stats::small_battery(&mut SHR3::new(1));
+    assert_eq!(failure_names(&report), ["matrix rank", "linear complexity"]);
+}
+
+#[test]
+fn test_lfsr_linear_complexity() {
+    // The most significant bit has linear complexity 88 or 113, well short of the 250 expected
+    // for 500 random bits.
+    let report = stats::small_battery(&mut LFSR88::new(1, 2, 3));
+    assert_eq!(failure_names(&report), ["linear complexity"]);
+    let report = stats::small_battery(&mut LFSR113::new(1, 2, 3, 4));
+    assert_eq!(failure_names(&report), ["linear complexity"]);
+}
+
+#[test]
+fn test_constant_generator() {
+    // A multiplier of 1 with no increment outputs the seed forever. It never outputs a value in
+    // the gap test's interval, which must fail rather than hang.
+    let mut rng = Lcg::<u32, 1, 0, 0>::new(u32::MAX);
+    assert_eq!(stats::gap(&mut rng, 1000), 0.0);
+    let report = stats::small_battery(&mut rng);
+    assert_eq!(report.p_value("gap"), Some(0.0));
+    assert!(!report.passed());
+}
+
+#[test]
+fn test_no_outputs() {
+    // With no outputs, the p-values are NaN, which must not pass.
+    let p_value = stats::monobit(&mut KISS::new(1, 2, 3, 4), 0);
+    assert!(p_value.is_nan());
+    let mut report = stats::Report::new();
+    report.push("monobit", p_value);
+    report.push("serial pairs", stats::serial_pairs(&mut KISS::new(1, 2, 3, 4), 0));
+    assert_eq!(failure_names(&report), ["monobit", "serial pairs"]);
+    assert!(!report.passed());
+}
+
+#[test]
+fn test_report_display() {
+    let mut report = stats::Report::new();
+    report.push("good", 0.5);
+    report.push("bad", 1e-6);
+    assert_eq!(
+        report.to_string(),
+        "good                 5.0000e-1    pass\nbad                  1.0000e-6    FAIL\n1 of 2 tests passed"
+    );
+    assert_eq!(report.p_value("bad"), Some(1e-6));
+    assert_eq!(report.p_value("missing"), None);
+    assert!(!report.passed());
+}
+
+#[test]
+fn test_bit_profile_cong() {
+    let profile = stats::bit_profile(&mut Cong::new(1), 10_000);
+    assert_eq!(profile.n(), 10_000);
+    assert_eq!(profile.bits().len(), 32);
+    // Bit 0 alternates, and bit i has period 2^(i+1), so low linear complexity.
+    assert_eq!(profile.bits()[0].linear_complexity, 2);
+    assert_eq!(profile.bits()[0].correlation, -1.0);
+    for i in 1..=12 {
+        assert_eq!(profile.bits()[i].linear_complexity, (1 << i) + 1);
+    }
+    assert_eq!(profile.trustworthy_mask(), 0xFFFF_E000);
+}
+
+#[test]
+fn test_bit_profile_good_generators() {
+    let profile = stats::bit_profile(&mut MWC1::new(1, 2), 10_000);
+    assert_eq!(profile.trustworthy_mask(), 0xFFFF_FFFF, "{}", profile);
+    let profile = stats::bit_profile(&mut KISS::new(1, 2, 3, 4), 10_000);
+    assert_eq!(profile.trustworthy_mask(), 0xFFFF_FFFF, "{}", profile);
+}
+
+#[test]
+fn test_bit_profile_lfsr113() {
+    // Each bit satisfies the generator's linear recurrence of degree 113.
+    let profile = stats::bit_profile(&mut LFSR113::new(1, 2, 3, 4), 10_000);
+    for b in profile.bits() {
+        assert_eq!(b.linear_complexity, 113);
+    }
+    assert_eq!(profile.trustworthy_mask(), 0);
+}